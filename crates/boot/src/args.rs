@@ -0,0 +1,148 @@
+use std::{fmt, path::PathBuf, str::FromStr, time::Duration};
+
+const USAGE: &str = "\
+Usage: boot [OPTIONS] [KERNEL] [-- QEMU_ARGS...]
+
+Boots the images created by the build script, or KERNEL if given. Test binaries
+are run headless and the exit code reflects whether they passed.
+
+Options:
+  -b, --boot <uefi|bios>   Boot mode (default: uefi)
+  -m, --memory <SIZE>      Memory size passed to QEMU's `-m` (default: 4G)
+  -c, --cpus <COUNT>       Number of virtual CPUs (default: 1)
+      --headless           Run without a display window
+      --gdb                Start a GDB stub on tcp::1234 and wait for a connection
+      --serial-log <FILE>  Also write serial output to FILE
+      --timeout <SECS>     Seconds a test binary may run for (default: 60)
+  -h, --help               Print this message
+
+Everything after `--` is passed to QEMU as is.";
+
+
+#[allow(clippy::upper_case_acronyms)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BootType {
+    UEFI,
+    BIOS,
+}
+
+impl FromStr for BootType {
+    type Err = ArgsError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().as_str() {
+            "uefi" => Ok(Self::UEFI),
+            "bios" => Ok(Self::BIOS),
+            _ => Err(ArgsError::InvalidValue { flag: "--boot", value: s.to_string() }),
+        }
+    }
+}
+
+
+#[derive(Debug)]
+pub struct Args {
+    pub boot_type: BootType,
+    pub memory: String,
+    pub cpus: u16,
+    pub headless: bool,
+    pub gdb: bool,
+    pub serial_log: Option<PathBuf>,
+    pub timeout: Duration,
+    pub kernel: Option<PathBuf>,
+    pub qemu_args: Vec<String>,
+}
+
+impl Default for Args {
+    fn default() -> Self {
+        Self {
+            boot_type: BootType::UEFI,
+            memory: String::from("4G"),
+            cpus: 1,
+            headless: false,
+            gdb: false,
+            serial_log: None,
+            timeout: Duration::from_secs(60),
+            kernel: None,
+            qemu_args: Vec::new(),
+        }
+    }
+}
+
+impl Args {
+    /// Parses the arguments, excluding the program name.
+    pub fn parse<I: IntoIterator<Item = String>>(iter: I) -> Result<Self, ArgsError> {
+        let mut args = Args::default();
+        let mut iter = iter.into_iter();
+
+        while let Some(arg) = iter.next() {
+            match arg.as_str() {
+                "-b" | "--boot" => args.boot_type = next_value(&mut iter, "--boot")?.parse()?,
+
+                "-m" | "--memory" => args.memory = next_value(&mut iter, "--memory")?,
+
+                "-c" | "--cpus" => {
+                    let value = next_value(&mut iter, "--cpus")?;
+
+                    args.cpus = value.parse()
+                        .ok()
+                        .filter(|&v| v != 0)
+                        .ok_or(ArgsError::InvalidValue { flag: "--cpus", value })?;
+                }
+
+                "--headless" => args.headless = true,
+
+                "--gdb" => args.gdb = true,
+
+                "--serial-log" => args.serial_log = Some(next_value(&mut iter, "--serial-log")?.into()),
+
+                "--timeout" => {
+                    let value = next_value(&mut iter, "--timeout")?;
+
+                    args.timeout = value.parse()
+                        .map(Duration::from_secs)
+                        .map_err(|_| ArgsError::InvalidValue { flag: "--timeout", value })?;
+                }
+
+                "-h" | "--help" => return Err(ArgsError::Help),
+
+                "--" => {
+                    args.qemu_args.extend(iter);
+                    break;
+                }
+
+                _ if args.kernel.is_none() && !arg.starts_with('-') => args.kernel = Some(arg.into()),
+
+                _ => return Err(ArgsError::Unknown(arg)),
+            }
+        }
+
+        Ok(args)
+    }
+}
+
+fn next_value<I: Iterator<Item = String>>(iter: &mut I, flag: &'static str) -> Result<String, ArgsError> {
+    iter.next().ok_or(ArgsError::MissingValue(flag))
+}
+
+
+#[derive(Debug)]
+pub enum ArgsError {
+    /// `--help` was requested.
+    Help,
+    Unknown(String),
+    MissingValue(&'static str),
+    InvalidValue { flag: &'static str, value: String },
+}
+
+impl fmt::Display for ArgsError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Help => write!(f, "{USAGE}"),
+            Self::Unknown(arg) => write!(f, "unknown argument '{arg}'\n\n{USAGE}"),
+            Self::MissingValue(flag) => write!(f, "missing value for '{flag}'"),
+            Self::InvalidValue { flag, value } => write!(f, "invalid value '{value}' for '{flag}'"),
+        }
+    }
+}
+
+impl std::error::Error for ArgsError {}
@@ -1,54 +1,88 @@
 #![feature(restricted_std, exit_status_error)]
 
-use std::{io::Write, process::{Command, Output, Stdio}, fs::File};
+use std::{path::PathBuf, process::Command};
 
-#[allow(clippy::upper_case_acronyms, unused)]
-enum BootType {
-    UEFI,
-    BIOS
-}
+mod args;
+mod runner;
 
-const BOOT_TYPE: BootType = BootType::UEFI;
+use args::{Args, ArgsError, BootType};
 
 fn main() -> Result<(), Box<dyn std::error::Error>> {
-    let out_dir   = env!("OUT_DIR");
     let uefi_path = env!("UEFI_PATH");
     let bios_path = env!("BIOS_PATH");
 
+    let args = match Args::parse(std::env::args().skip(1)) {
+        Ok(v) => v,
+
+        Err(ArgsError::Help) => {
+            println!("{}", ArgsError::Help);
+            return Ok(());
+        }
+
+        Err(e) => {
+            eprintln!("error: {e}");
+            std::process::exit(2);
+        }
+    };
+
+    // Set when invoked as the cargo runner for a kernel test binary.
+    let is_test = matches!(&args.kernel, Some(kernel) if runner::is_test(kernel));
+
+    let image = match &args.kernel {
+        Some(kernel) => runner::create_disk_image(kernel, args.boot_type)?,
+
+        None => PathBuf::from(match args.boot_type {
+            BootType::UEFI => uefi_path,
+            BootType::BIOS => bios_path,
+        }),
+    };
+
     let mut cmd = Command::new("qemu-system-x86_64");
     cmd
-        // Freeze QEMU instead of rebooting
-        .args([ "-action", "reboot=shutdown,shutdown=pause" ])
-        // Send serial output to stdout
-        .args([ "-serial", "stdio" ])
-        // Display options
-        // .args([ "-display", "gtk,gl=on,full-screen=on" ])
-        // Increase memory available to QEMU
-        .args([ "-m", "4G" ]);
-
-
-    match BOOT_TYPE {
-        BootType::UEFI => {
-            cmd.arg("-bios")
-                .arg(ovmf_prebuilt::ovmf_pure_efi());
-            cmd.arg("-drive")
-                .arg(format!("format=raw,file={uefi_path}"));
-
-            println!("UEFI img located at: {uefi_path}");
-        },
-        BootType::BIOS => {
-            cmd.arg("-drive")
-                .arg(format!("format=raw,file={bios_path}"));
-
-            println!("BIOS img located at: {bios_path}");
-        },
+        // Freeze QEMU instead of rebooting. Tests have to exit so their result can be read.
+        .args([ "-action", if is_test { "reboot=shutdown" } else { "reboot=shutdown,shutdown=pause" } ])
+        .args([ "-m", &args.memory ])
+        .args([ "-smp", &args.cpus.to_string() ]);
+
+    // Send serial output to stdout, optionally mirroring it into a log file.
+    if let Some(log) = &args.serial_log {
+        cmd.arg("-chardev")
+            .arg(format!("stdio,id=serial0,signal=off,logfile={}", log.display()));
+        cmd.args([ "-serial", "chardev:serial0" ]);
+    } else {
+        cmd.args([ "-serial", "stdio" ]);
     }
 
-    let mut child = cmd.spawn().unwrap();
+    if args.headless || is_test {
+        cmd.args([ "-display", "none" ]);
+    }
 
-    let wait = child.wait().unwrap();
-    wait.exit_ok().unwrap();
+    // Wait for a debugger on tcp::1234 before starting the CPU.
+    if args.gdb {
+        cmd.args([ "-s", "-S" ]);
+
+        println!("Waiting for GDB on tcp::1234");
+    }
+
+    if args.boot_type == BootType::UEFI {
+        cmd.arg("-bios")
+            .arg(ovmf_prebuilt::ovmf_pure_efi());
+    }
+
+    cmd.arg("-drive")
+        .arg(format!("format=raw,file={}", image.display()));
+
+    cmd.args(&args.qemu_args);
+
+    if is_test {
+        return runner::run_test(cmd, args.timeout);
+    }
+
+    println!("{:?} img located at: {}", args.boot_type, image.display());
+
+    let mut child = cmd.spawn()?;
+
+    child.wait()?.exit_ok()?;
 
     Ok(())
 }
-
@@ -0,0 +1,69 @@
+use std::{
+    error::Error,
+    ffi::OsStr,
+    path::{Path, PathBuf},
+    process::Command,
+    thread,
+    time::{Duration, Instant},
+};
+
+use crate::args::BootType;
+
+/// Must match `kernel::testing::QEMU_EXIT_PORT`.
+const QEMU_EXIT_PORT: u16 = 0xF4;
+
+/// `kernel::QemuExitCode::Success` as QEMU reports it, `(code << 1) | 1`.
+const QEMU_EXIT_SUCCESS: i32 = (0x10 << 1) | 1;
+
+
+/// Cargo places test binaries inside of `<profile>/deps`, while the kernel binary lives in `<profile>` itself.
+pub fn is_test(kernel: &Path) -> bool {
+    kernel.parent().and_then(Path::file_name) == Some(OsStr::new("deps"))
+}
+
+/// Creates a bootable disk image next to `kernel`.
+pub fn create_disk_image(kernel: &Path, boot_type: BootType) -> Result<PathBuf, Box<dyn Error>> {
+    match boot_type {
+        BootType::UEFI => {
+            let image = kernel.with_extension("uefi.img");
+            bootloader::UefiBoot::new(kernel).create_disk_image(&image)?;
+            Ok(image)
+        }
+
+        BootType::BIOS => {
+            let image = kernel.with_extension("bios.img");
+            bootloader::BiosBoot::new(kernel).create_disk_image(&image)?;
+            Ok(image)
+        }
+    }
+}
+
+/// Runs a test binary, killing QEMU if it doesn't exit within `timeout`.
+pub fn run_test(mut cmd: Command, timeout: Duration) -> Result<(), Box<dyn Error>> {
+    cmd.arg("-device")
+        .arg(format!("isa-debug-exit,iobase={QEMU_EXIT_PORT:#x},iosize=0x04"));
+
+    let mut child = cmd.spawn()?;
+    let started = Instant::now();
+
+    let status = loop {
+        if let Some(status) = child.try_wait()? {
+            break status;
+        }
+
+        if started.elapsed() >= timeout {
+            child.kill()?;
+            child.wait()?;
+
+            return Err(format!("test timed out after {}s", timeout.as_secs()).into());
+        }
+
+        thread::sleep(Duration::from_millis(100));
+    };
+
+    match status.code() {
+        Some(QEMU_EXIT_SUCCESS) => Ok(()),
+        Some(code) => Err(format!("test failed with QEMU exit code {code}").into()),
+        None => Err("QEMU was terminated by a signal".into()),
+    }
+}
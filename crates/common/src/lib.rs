@@ -1,13 +1,15 @@
 #![no_std]
 
+#[cfg(test)]
+extern crate std;
+
 extern crate alloc;
 
 use core::ops::{SubAssign, AddAssign};
 
 pub mod user;
-pub mod iter;
 
-#[derive(Debug, PartialEq, Eq, PartialOrd, Ord)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
 pub struct Dimensions<V>(V, V);
 
 impl<V> Dimensions<V> {
@@ -0,0 +1,198 @@
+use core::mem;
+
+use alloc::{collections::VecDeque, vec::Vec};
+
+
+/// Previously entered input lines, oldest first. Once full the oldest line is dropped.
+pub struct History {
+    entries: VecDeque<Vec<char>>,
+    capacity: usize,
+
+    /// Entry currently recalled with [`History::older`]/[`History::newer`].
+    browsing: Option<usize>,
+    /// The line which was being typed before browsing started.
+    draft: Vec<char>,
+}
+
+impl History {
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            entries: VecDeque::with_capacity(capacity),
+            capacity,
+
+            browsing: None,
+            draft: Vec::new(),
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    pub fn get(&self, index: usize) -> Option<&[char]> {
+        self.entries.get(index).map(Vec::as_slice)
+    }
+
+    /// Stores a submitted line and stops browsing. Blank lines and repeats of the last line are skipped.
+    pub fn push(&mut self, line: Vec<char>) {
+        self.browsing = None;
+        self.draft.clear();
+
+        if line.iter().all(|c| c.is_whitespace()) || self.entries.back() == Some(&line) {
+            return;
+        }
+
+        if self.entries.len() == self.capacity {
+            self.entries.pop_front();
+        }
+
+        self.entries.push_back(line);
+    }
+
+    /// Steps to the next older line. `current` is kept so [`History::newer`] can return to it.
+    pub fn older(&mut self, current: &[char]) -> Option<&[char]> {
+        let index = match self.browsing {
+            Some(0) => return None,
+            Some(index) => index - 1,
+
+            None => {
+                self.draft = current.to_vec();
+                self.entries.len().checked_sub(1)?
+            }
+        };
+
+        self.browsing = Some(index);
+        self.get(index)
+    }
+
+    /// Steps to the next newer line, ending with the line that was being typed before browsing.
+    pub fn newer(&mut self) -> Option<Vec<char>> {
+        let index = self.browsing? + 1;
+
+        if index < self.entries.len() {
+            self.browsing = Some(index);
+            self.get(index).map(<[char]>::to_vec)
+        } else {
+            self.browsing = None;
+            Some(mem::take(&mut self.draft))
+        }
+    }
+
+    /// Index of the newest line before `before` which contains `query`.
+    pub fn search(&self, query: &[char], before: usize) -> Option<usize> {
+        if query.is_empty() {
+            return None;
+        }
+
+        self.entries.range(..before.min(self.entries.len()))
+            .rposition(|line| line.windows(query.len()).any(|w| w == query))
+    }
+}
+
+
+/// State of an incremental reverse search (Ctrl+R) through a [`History`].
+#[derive(Default)]
+pub struct ReverseSearch {
+    query: Vec<char>,
+    found: Option<usize>,
+}
+
+impl ReverseSearch {
+    pub fn query(&self) -> &[char] {
+        &self.query
+    }
+
+    pub fn found<'a>(&self, history: &'a History) -> Option<&'a [char]> {
+        history.get(self.found?)
+    }
+
+    /// Extends the query. The current match is kept if it still matches.
+    pub fn push(&mut self, value: char, history: &History) {
+        self.query.push(value);
+
+        let before = self.found.map_or(history.len(), |i| i + 1);
+        self.found = history.search(&self.query, before);
+    }
+
+    pub fn pop(&mut self, history: &History) {
+        self.query.pop();
+        self.found = history.search(&self.query, history.len());
+    }
+
+    /// Moves to the next older match, staying on the current one if there is none.
+    pub fn next(&mut self, history: &History) {
+        let before = self.found.unwrap_or(history.len());
+
+        if let Some(index) = history.search(&self.query, before) {
+            self.found = Some(index);
+        }
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use alloc::string::String;
+
+    use super::*;
+
+    fn history(lines: &[&str]) -> History {
+        let mut history = History::new(4);
+
+        for line in lines {
+            history.push(line.chars().collect());
+        }
+
+        history
+    }
+
+    fn string(value: Option<&[char]>) -> Option<String> {
+        value.map(|v| v.iter().collect())
+    }
+
+    #[test]
+    fn browse() {
+        let mut h = history(&["one", "two"]);
+        let draft = ['t', 'h'];
+
+        assert_eq!(string(h.older(&draft)).as_deref(), Some("two"));
+        assert_eq!(string(h.older(&[])).as_deref(), Some("one"));
+        assert_eq!(h.older(&[]), None);
+
+        assert_eq!(h.newer(), Some("two".chars().collect()));
+        assert_eq!(h.newer(), Some(draft.to_vec()));
+        assert_eq!(h.newer(), None);
+    }
+
+    #[test]
+    fn skips_blank_and_repeated_lines() {
+        let h = history(&["one", "  ", "one", "two", "three", "four", "five"]);
+
+        assert_eq!(h.len(), 4);
+        assert_eq!(string(h.get(0)).as_deref(), Some("two"));
+    }
+
+    #[test]
+    fn reverse_search() {
+        let h = history(&["echo a", "mem", "echo b"]);
+        let mut search = ReverseSearch::default();
+
+        search.push('e', &h);
+        assert_eq!(string(search.found(&h)).as_deref(), Some("echo b"));
+
+        search.push('m', &h);
+        assert_eq!(string(search.found(&h)).as_deref(), Some("mem"));
+
+        search.pop(&h);
+        search.next(&h);
+        assert_eq!(string(search.found(&h)).as_deref(), Some("mem"));
+
+        search.next(&h);
+        search.next(&h);
+        assert_eq!(string(search.found(&h)).as_deref(), Some("echo a"));
+    }
+}
@@ -4,6 +4,30 @@ use alloc::vec::Vec;
 
 use crate::{Position, Dimensions};
 
+mod history;
+
+pub use history::{History, ReverseSearch};
+
+
+/// An edit applied to the input line at the cursor.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LineEdit {
+    Left,
+    Right,
+    Home,
+    End,
+    WordLeft,
+    WordRight,
+    /// Remove the character before the cursor.
+    Backspace,
+    /// Remove the character under the cursor.
+    Delete,
+    /// Remove everything before the cursor.
+    KillToStart,
+    /// Remove everything from the cursor onwards.
+    KillToEnd,
+}
+
 
 #[derive(Default)]
 pub struct ConsoleCursor {
@@ -13,6 +37,8 @@ pub struct ConsoleCursor {
     displayed: bool,
 
     input: Vec<char>,
+    /// Position of the cursor inside of `input`.
+    index: usize,
 }
 
 impl ConsoleCursor {
@@ -45,29 +71,80 @@ impl ConsoleCursor {
         self.pos.set_y(value);
     }
 
-    pub fn backspace(&mut self) {
-        // TODO: Check where we are in the input.
-        self.input.pop();
+    pub fn input(&self) -> &[char] {
+        &self.input
+    }
 
-        if self.pos.x() != 0 {
-            self.pos.dec_x(1);
-        }
+    pub fn index(&self) -> usize {
+        self.index
+    }
+
+    /// The character the cursor is currently over.
+    pub fn current_char(&self) -> Option<char> {
+        self.input.get(self.index).copied()
+    }
+
+    /// The part of the input which fits in `display_dimensions`, scrolled so the cursor is always visible.
+    pub fn visible_input(&self, display_dimensions: Dimensions<u16>) -> &[char] {
+        let offset = self.view_offset(display_dimensions);
+        let end = self.input.len().min(offset + display_dimensions.width() as usize);
+
+        &self.input[offset..end]
+    }
+
+    pub fn backspace(&mut self, display_dimensions: Dimensions<u16>) {
+        self.edit(LineEdit::Backspace, display_dimensions);
     }
 
     pub fn take_input(&mut self) -> Vec<char> {
+        self.index = 0;
         self.pos.set_x(0);
 
         mem::take(&mut self.input)
     }
 
+    /// Replaces the whole input, placing the cursor at its end.
+    pub fn set_input(&mut self, value: Vec<char>, display_dimensions: Dimensions<u16>) {
+        self.index = value.len();
+        self.input = value;
+
+        self.update_x(display_dimensions);
+    }
+
     pub fn insert_input(&mut self, value: char, display_dimensions: Dimensions<u16>) {
-        self.input.push(value);
+        self.input.insert(self.index, value);
+        self.index += 1;
+
+        self.update_x(display_dimensions);
+    }
+
+    pub fn edit(&mut self, edit: LineEdit, display_dimensions: Dimensions<u16>) {
+        match edit {
+            LineEdit::Left => self.index = self.index.saturating_sub(1),
+            LineEdit::Right => self.index = (self.index + 1).min(self.input.len()),
+            LineEdit::Home => self.index = 0,
+            LineEdit::End => self.index = self.input.len(),
+            LineEdit::WordLeft => self.index = self.word_left(),
+            LineEdit::WordRight => self.index = self.word_right(),
+
+            LineEdit::Backspace => if self.index != 0 {
+                self.index -= 1;
+                self.input.remove(self.index);
+            }
+
+            LineEdit::Delete => if self.index < self.input.len() {
+                self.input.remove(self.index);
+            }
+
+            LineEdit::KillToStart => {
+                self.input.drain(..self.index);
+                self.index = 0;
+            }
 
-        if self.pos.x() + 1 >= display_dimensions.width() {
-            // TODO: handle
-        } else {
-            self.pos.inc_x(1);
+            LineEdit::KillToEnd => self.input.truncate(self.index),
         }
+
+        self.update_x(display_dimensions);
     }
 
     pub fn move_me(&mut self, horiz: i32, vert: i32) {
@@ -94,4 +171,122 @@ impl ConsoleCursor {
             _ => ()
         }
     }
-}
\ No newline at end of file
+
+    /// Start of the word before the cursor.
+    fn word_left(&self) -> usize {
+        let mut index = self.index;
+
+        while index != 0 && self.input[index - 1].is_whitespace() {
+            index -= 1;
+        }
+
+        while index != 0 && !self.input[index - 1].is_whitespace() {
+            index -= 1;
+        }
+
+        index
+    }
+
+    /// End of the word after the cursor.
+    fn word_right(&self) -> usize {
+        let mut index = self.index;
+
+        while index < self.input.len() && self.input[index].is_whitespace() {
+            index += 1;
+        }
+
+        while index < self.input.len() && !self.input[index].is_whitespace() {
+            index += 1;
+        }
+
+        index
+    }
+
+    fn view_offset(&self, display_dimensions: Dimensions<u16>) -> usize {
+        self.index.saturating_sub(display_dimensions.width().saturating_sub(1) as usize)
+    }
+
+    fn update_x(&mut self, display_dimensions: Dimensions<u16>) {
+        let offset = self.view_offset(display_dimensions);
+        self.pos.set_x((self.index - offset) as u16);
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn cursor(value: &str) -> ConsoleCursor {
+        let mut cursor = ConsoleCursor::default();
+
+        for c in value.chars() {
+            cursor.insert_input(c, dims());
+        }
+
+        cursor
+    }
+
+    fn dims() -> Dimensions<u16> {
+        Dimensions::from((10, 1))
+    }
+
+    fn input(cursor: &ConsoleCursor) -> alloc::string::String {
+        cursor.input().iter().collect()
+    }
+
+    #[test]
+    fn insert_and_delete_mid_line() {
+        let mut c = cursor("helo");
+
+        c.edit(LineEdit::Left, dims());
+        c.insert_input('l', dims());
+        assert_eq!(input(&c), "hello");
+        assert_eq!(c.index(), 4);
+
+        c.edit(LineEdit::Home, dims());
+        c.edit(LineEdit::Delete, dims());
+        assert_eq!(input(&c), "ello");
+
+        c.edit(LineEdit::End, dims());
+        c.backspace(dims());
+        assert_eq!(input(&c), "ell");
+        assert_eq!(c.pos().x(), 3);
+    }
+
+    #[test]
+    fn word_movement() {
+        let mut c = cursor("one  two three");
+
+        c.edit(LineEdit::WordLeft, dims());
+        assert_eq!(c.index(), 9);
+
+        c.edit(LineEdit::WordLeft, dims());
+        assert_eq!(c.index(), 5);
+
+        c.edit(LineEdit::WordRight, dims());
+        assert_eq!(c.index(), 8);
+    }
+
+    #[test]
+    fn kill() {
+        let mut c = cursor("hello world");
+
+        c.edit(LineEdit::WordLeft, dims());
+        c.edit(LineEdit::KillToStart, dims());
+        assert_eq!(input(&c), "world");
+        assert_eq!(c.index(), 0);
+
+        c.edit(LineEdit::Right, dims());
+        c.edit(LineEdit::KillToEnd, dims());
+        assert_eq!(input(&c), "w");
+    }
+
+    #[test]
+    fn scrolls_past_width() {
+        let c = cursor("0123456789abc");
+
+        assert_eq!(c.pos().x(), 9);
+        assert_eq!(c.visible_input(dims()).iter().collect::<alloc::string::String>(), "456789abc");
+    }
+}
@@ -0,0 +1,310 @@
+//! Just enough of the Glyph Bitmap Distribution Format to read monospaced fonts.
+
+use std::{error::Error, fmt};
+
+
+pub struct Font {
+    /// Size of every cell, from `FONTBOUNDINGBOX`.
+    pub width: u16,
+    pub height: u16,
+
+    /// Sorted by character.
+    pub glyphs: Vec<Glyph>,
+}
+
+pub struct Glyph {
+    pub char: char,
+    pub width: u16,
+    pub height: u16,
+    /// Distance from the left and bottom of the cell.
+    pub off_x: isize,
+    pub off_y: isize,
+    /// A bit per pixel, most significant bit first, with every row starting on a new byte.
+    pub raster: Vec<u8>,
+}
+
+impl Font {
+    /// Draws every glyph a pixel wider, by setting each pixel right of a set one. Cells are a pixel wider too.
+    pub fn embolden(&mut self) {
+        self.width += 1;
+
+        for glyph in &mut self.glyphs {
+            let row_size = glyph.row_size();
+            glyph.width += 1;
+
+            let mut raster = Vec::with_capacity(glyph.row_size() * glyph.height as usize);
+
+            for row in glyph.raster.chunks(row_size.max(1)).take(glyph.height as usize) {
+                let mut carry = 0;
+
+                for &byte in row {
+                    raster.push(byte | byte >> 1 | carry);
+                    carry = byte << 7;
+                }
+
+                // The last pixel moved onto a new byte.
+                if glyph.row_size() > row_size {
+                    raster.push(carry);
+                }
+            }
+
+            glyph.raster = raster;
+        }
+    }
+}
+
+impl Glyph {
+    pub fn row_size(&self) -> usize {
+        (self.width as usize + 7) / 8
+    }
+}
+
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct ParseError {
+    /// One based, or `0` if it's about the whole file.
+    pub line: usize,
+    pub message: String,
+}
+
+impl ParseError {
+    fn new(line: usize, message: impl Into<String>) -> Self {
+        Self { line, message: message.into() }
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.line {
+            0 => write!(f, "{}", self.message),
+            line => write!(f, "line {line}: {}", self.message),
+        }
+    }
+}
+
+impl Error for ParseError {}
+
+
+/// Bounding box of a glyph, or of the whole font: width, height, x offset and y offset.
+type BoundingBox = (u16, u16, isize, isize);
+
+/// Reads a font, keeping only the glyphs which have a unicode encoding. Glyphs which don't fit in the font's
+/// bounding box are an error.
+pub fn parse(source: &str) -> Result<Font, ParseError> {
+    let mut lines = source.lines().enumerate().map(|(i, line)| (i + 1, line.trim()));
+
+    let mut bounds: Option<BoundingBox> = None;
+    let mut glyphs = Vec::new();
+
+    while let Some((number, line)) = lines.next() {
+        let (keyword, rest) = line.split_once(' ').unwrap_or((line, ""));
+
+        match keyword {
+            "FONTBOUNDINGBOX" => bounds = Some(bounding_box(number, rest)?),
+
+            "STARTCHAR" => {
+                let bounds = bounds.ok_or_else(|| ParseError::new(number, "STARTCHAR before FONTBOUNDINGBOX"))?;
+
+                if let Some(glyph) = parse_glyph(&mut lines, bounds)? {
+                    glyphs.push((number, glyph));
+                }
+            }
+
+            _ => (),
+        }
+    }
+
+    let (width, height, ..) = bounds.ok_or_else(|| ParseError::new(0, "missing FONTBOUNDINGBOX"))?;
+
+    glyphs.sort_by_key(|(_, glyph)| glyph.char);
+
+    for pair in glyphs.windows(2) {
+        if pair[0].1.char == pair[1].1.char {
+            return Err(ParseError::new(pair[1].0, format!("{:?} is encoded twice", pair[1].1.char)));
+        }
+    }
+
+    Ok(Font {
+        width,
+        height,
+        glyphs: glyphs.into_iter().map(|(_, glyph)| glyph).collect(),
+    })
+}
+
+/// Reads up to and including `ENDCHAR`. `None` if the glyph has no unicode encoding.
+fn parse_glyph<'a>(
+    lines: &mut impl Iterator<Item = (usize, &'a str)>,
+    (font_width, font_height, font_x, font_y): BoundingBox,
+) -> Result<Option<Glyph>, ParseError> {
+    let mut char = None;
+    let mut bounds = None;
+    let mut raster = None;
+
+    while let Some((number, line)) = lines.next() {
+        let (keyword, rest) = line.split_once(' ').unwrap_or((line, ""));
+
+        match keyword {
+            "ENCODING" => {
+                let code = rest.split_whitespace().next().and_then(|code| code.parse::<i64>().ok());
+
+                char = match code {
+                    // Glyphs without a standard encoding.
+                    Some(-1) => None,
+                    Some(code) => Some(u32::try_from(code).ok().and_then(char::from_u32)
+                        .ok_or_else(|| ParseError::new(number, format!("{code} isn't a character")))?),
+                    None => return Err(ParseError::new(number, "invalid ENCODING")),
+                };
+            }
+
+            "BBX" => bounds = Some((number, bounding_box(number, rest)?)),
+
+            "BITMAP" => {
+                let (_, (width, height, ..)) = bounds.ok_or_else(|| ParseError::new(number, "BITMAP before BBX"))?;
+                let row_size = (width as usize + 7) / 8;
+                let mut bytes = Vec::with_capacity(row_size * height as usize);
+
+                for _ in 0..height {
+                    let (number, row) = lines.next().ok_or_else(|| ParseError::new(number, "missing BITMAP rows"))?;
+                    bytes.extend(hex_row(number, row, row_size)?);
+                }
+
+                raster = Some(bytes);
+            }
+
+            "ENDCHAR" => {
+                let Some(char) = char else {
+                    return Ok(None);
+                };
+
+                let (number, (width, height, x, y)) = bounds.ok_or_else(|| ParseError::new(number, "missing BBX"))?;
+                let (off_x, off_y) = (x - font_x, y - font_y);
+
+                if off_x < 0 || off_y < 0 || off_x + width as isize > font_width as isize || off_y + height as isize > font_height as isize {
+                    return Err(ParseError::new(number, format!("{char:?} doesn't fit in FONTBOUNDINGBOX")));
+                }
+
+                return Ok(Some(Glyph {
+                    char,
+                    width,
+                    height,
+                    off_x,
+                    off_y,
+                    raster: raster.unwrap_or_else(|| vec![0; (width as usize + 7) / 8 * height as usize]),
+                }));
+            }
+
+            _ => (),
+        }
+    }
+
+    Err(ParseError::new(0, "missing ENDCHAR"))
+}
+
+fn bounding_box(number: usize, values: &str) -> Result<BoundingBox, ParseError> {
+    let invalid = || ParseError::new(number, "invalid bounding box");
+    let mut values = values.split_whitespace();
+
+    let mut next = || values.next().ok_or_else(invalid);
+    let (width, height, x, y) = (next()?, next()?, next()?, next()?);
+
+    Ok((
+        width.parse().map_err(|_| invalid())?,
+        height.parse().map_err(|_| invalid())?,
+        x.parse().map_err(|_| invalid())?,
+        y.parse().map_err(|_| invalid())?,
+    ))
+}
+
+/// A row of a bitmap, which may have more bytes than `row_size` but never less.
+fn hex_row(number: usize, row: &str, row_size: usize) -> Result<Vec<u8>, ParseError> {
+    if row.len() < row_size * 2 || !row.is_ascii() {
+        return Err(ParseError::new(number, "BITMAP row is too short"));
+    }
+
+    (0..row_size)
+        .map(|i| u8::from_str_radix(&row[i * 2..i * 2 + 2], 16).map_err(|_| ParseError::new(number, "invalid BITMAP row")))
+        .collect()
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const FONT: &str = "\
+STARTFONT 2.1
+FONT -test-fixed-medium-r-normal--4-40-75-75-c-40-iso10646-1
+SIZE 4 75 75
+FONTBOUNDINGBOX 4 4 0 -1
+STARTPROPERTIES 1
+FONT_ASCENT 3
+ENDPROPERTIES
+CHARS 3
+STARTCHAR B
+ENCODING 66
+BBX 2 3 1 0
+BITMAP
+80
+C0
+40
+ENDCHAR
+STARTCHAR A
+ENCODING 65
+BBX 3 1 0 -1
+BITMAP
+E0
+ENDCHAR
+STARTCHAR unencoded
+ENCODING -1
+BBX 1 1 0 0
+BITMAP
+80
+ENDCHAR
+ENDFONT
+";
+
+    #[test]
+    fn glyphs() {
+        let font = parse(FONT).unwrap();
+
+        assert_eq!((font.width, font.height), (4, 4));
+        assert_eq!(font.glyphs.iter().map(|glyph| glyph.char).collect::<String>(), "AB");
+
+        let b = &font.glyphs[1];
+        assert_eq!((b.width, b.height, b.off_x, b.off_y), (2, 3, 1, 1));
+        assert_eq!(b.raster, [0x80, 0xC0, 0x40]);
+    }
+
+    #[test]
+    fn embolden() {
+        let mut font = parse(FONT).unwrap();
+        font.embolden();
+
+        assert_eq!(font.width, 5);
+
+        let b = &font.glyphs[1];
+        assert_eq!(b.width, 3);
+        assert_eq!(b.raster, [0xC0, 0xE0, 0x60]);
+
+        // Eight pixels become nine, which takes another byte.
+        let glyph = Glyph { char: 'x', width: 8, height: 1, off_x: 0, off_y: 0, raster: vec![0x81] };
+        let mut font = Font { width: 8, height: 1, glyphs: vec![glyph] };
+        font.embolden();
+
+        assert_eq!(font.glyphs[0].raster, [0xC1, 0x80]);
+    }
+
+    #[test]
+    fn glyph_outside_of_the_cell() {
+        let font = FONT.replace("BBX 2 3 1 0", "BBX 2 3 3 0");
+
+        assert_eq!(parse(&font).err().map(|e| e.line), Some(11));
+    }
+
+    #[test]
+    fn duplicate_encoding() {
+        let font = FONT.replace("ENCODING 66", "ENCODING 65");
+
+        assert!(parse(&font).is_err());
+    }
+}
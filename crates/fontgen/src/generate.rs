@@ -0,0 +1,64 @@
+//! Writes a font out as the kernel's `font/glyphs.rs`.
+
+use std::fmt::Write;
+
+use crate::bdf::Font;
+
+
+pub fn rust_source(font: &Font, source_name: &str) -> String {
+    let mut out = String::new();
+
+    // Writing into a string can't fail.
+    let _ = write_source(&mut out, font, source_name);
+
+    out
+}
+
+fn write_source(out: &mut String, font: &Font, source_name: &str) -> std::fmt::Result {
+    writeln!(out, "//! Glyphs of the builtin font, generated by `fontgen` from `{source_name}`. Edit that instead.")?;
+    writeln!(out)?;
+    writeln!(out, "use super::FontGlyph;")?;
+    writeln!(out)?;
+    writeln!(out, "pub const FONT_WIDTH: u16 = {};", font.width)?;
+    writeln!(out, "pub const FONT_HEIGHT: u16 = {};", font.height)?;
+    writeln!(out)?;
+    writeln!(out, "pub const FONTS: &[FontGlyph] = &[")?;
+
+    for glyph in &font.glyphs {
+        writeln!(
+            out,
+            "    FontGlyph::new({:?}, ({}, {}), ({}, {}), &[",
+            glyph.char, glyph.width, glyph.height, glyph.off_x, glyph.off_y,
+        )?;
+
+        for row in glyph.raster.chunks(glyph.row_size().max(1)) {
+            let bytes = row.iter().map(|byte| format!("0b{byte:08b},")).collect::<Vec<_>>();
+            writeln!(out, "        {}", bytes.join(" "))?;
+        }
+
+        writeln!(out, "    ]),")?;
+    }
+
+    writeln!(out, "];")
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::bdf::Glyph;
+
+    #[test]
+    fn glyph_entries() {
+        let font = Font {
+            width: 10,
+            height: 2,
+            glyphs: vec![Glyph { char: '\'', width: 9, height: 1, off_x: 1, off_y: 1, raster: vec![0x80, 0x80] }],
+        };
+
+        let source = rust_source(&font, "test.bdf");
+
+        assert!(source.contains("pub const FONT_WIDTH: u16 = 10;"));
+        assert!(source.contains("    FontGlyph::new('\\'', (9, 1), (1, 1), &[\n        0b10000000, 0b10000000,\n    ]),\n"));
+    }
+}
@@ -0,0 +1,80 @@
+//! Converts a BDF font into the glyph table of the kernel's builtin font, or into a PSF font.
+
+use std::{error::Error, io::Write, path::PathBuf};
+
+mod bdf;
+mod generate;
+mod psf;
+
+const USAGE: &str = "\
+Usage: fontgen <BDF> [-o <FILE>] [--psf1 | --psf2] [--bold]
+
+Converts a monospaced BDF font into the kernel's `font/glyphs.rs`, or into a
+PSF font with --psf1 or --psf2, written to FILE or to stdout. Glyphs without a
+unicode encoding are left out. --bold draws every glyph a pixel wider.";
+
+
+enum Format {
+    Rust,
+    Psf1,
+    Psf2,
+}
+
+
+fn main() -> Result<(), Box<dyn Error>> {
+    let mut input = None;
+    let mut output = None;
+    let mut format = Format::Rust;
+    let mut bold = false;
+
+    let mut args = std::env::args().skip(1);
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "-o" | "--output" => output = Some(PathBuf::from(args.next().ok_or("-o needs a file")?)),
+
+            "--psf1" => format = Format::Psf1,
+            "--psf2" => format = Format::Psf2,
+            "--bold" => bold = true,
+
+            "-h" | "--help" => {
+                println!("{USAGE}");
+                return Ok(());
+            }
+
+            _ if input.is_none() && !arg.starts_with('-') => input = Some(PathBuf::from(arg)),
+
+            _ => {
+                eprintln!("error: unexpected argument `{arg}`\n\n{USAGE}");
+                std::process::exit(2);
+            }
+        }
+    }
+
+    let Some(input) = input else {
+        eprintln!("{USAGE}");
+        std::process::exit(2);
+    };
+
+    let mut font = bdf::parse(&std::fs::read_to_string(&input)?)
+        .map_err(|e| format!("{}: {e}", input.display()))?;
+
+    if bold {
+        font.embolden();
+    }
+
+    let name = input.file_name().unwrap_or_default().to_string_lossy();
+
+    let bytes = match format {
+        Format::Rust => generate::rust_source(&font, &name).into_bytes(),
+        Format::Psf1 => psf::psf1(&font)?,
+        Format::Psf2 => psf::psf2(&font),
+    };
+
+    match output {
+        Some(output) => std::fs::write(output, bytes)?,
+        None => std::io::stdout().write_all(&bytes)?,
+    }
+
+    Ok(())
+}
@@ -0,0 +1,145 @@
+//! Writes a font out as a PC Screen Font, with a unicode table mapping every glyph to its character.
+
+use crate::bdf::{Font, Glyph};
+
+const PSF1_MAGIC: [u8; 2] = [0x36, 0x04];
+const PSF1_MODE_512: u8 = 0x01;
+const PSF1_MODE_HAS_TABLE: u8 = 0x02;
+const PSF1_SEPARATOR: u16 = 0xFFFF;
+
+const PSF2_MAGIC: [u8; 4] = [0x72, 0xB5, 0x4A, 0x86];
+const PSF2_HEADER_SIZE: u32 = 32;
+const PSF2_HAS_TABLE: u32 = 0x01;
+const PSF2_SEPARATOR: u8 = 0xFF;
+
+
+/// Version 1 only has 8 pixel wide cells, and up to 512 glyphs of characters below U+FFFE.
+pub fn psf1(font: &Font) -> Result<Vec<u8>, String> {
+    if font.width > 8 {
+        return Err(format!("PSF1 cells are 8 pixels wide, not {}", font.width));
+    }
+
+    let height = u8::try_from(font.height).map_err(|_| format!("PSF1 cells can't be {} pixels high", font.height))?;
+
+    let (mode, count) = match font.glyphs.len() {
+        0..=256 => (PSF1_MODE_HAS_TABLE, 256),
+        257..=512 => (PSF1_MODE_HAS_TABLE | PSF1_MODE_512, 512),
+        count => return Err(format!("PSF1 holds up to 512 glyphs, not {count}")),
+    };
+
+    let mut out = vec![PSF1_MAGIC[0], PSF1_MAGIC[1], mode, height];
+
+    for glyph in &font.glyphs {
+        out.extend(cell(font, glyph));
+    }
+
+    out.resize(out.len() + (count - font.glyphs.len()) * font.height as usize, 0);
+
+    for index in 0..count {
+        if let Some(glyph) = font.glyphs.get(index) {
+            let char = u16::try_from(glyph.char as u32)
+                .ok()
+                .filter(|&char| char < 0xFFFE)
+                .ok_or_else(|| format!("PSF1 can't encode {:?}", glyph.char))?;
+
+            out.extend(char.to_le_bytes());
+        }
+
+        out.extend(PSF1_SEPARATOR.to_le_bytes());
+    }
+
+    Ok(out)
+}
+
+pub fn psf2(font: &Font) -> Vec<u8> {
+    let glyph_size = (font.width as u32 + 7) / 8 * font.height as u32;
+
+    let mut out = PSF2_MAGIC.to_vec();
+
+    for field in [0, PSF2_HEADER_SIZE, PSF2_HAS_TABLE, font.glyphs.len() as u32, glyph_size, font.height as u32, font.width as u32] {
+        out.extend(field.to_le_bytes());
+    }
+
+    for glyph in &font.glyphs {
+        out.extend(cell(font, glyph));
+    }
+
+    for glyph in &font.glyphs {
+        out.extend(glyph.char.encode_utf8(&mut [0; 4]).as_bytes());
+        out.push(PSF2_SEPARATOR);
+    }
+
+    out
+}
+
+
+/// Draws a glyph into a raster the size of the whole cell, which is how PSF stores them.
+fn cell(font: &Font, glyph: &Glyph) -> Vec<u8> {
+    let row_size = (font.width as usize + 7) / 8;
+    let mut out = vec![0; row_size * font.height as usize];
+
+    // The glyph's offset is from the bottom left of the cell, while rows go from the top.
+    let top = (font.height - glyph.height) as usize - glyph.off_y as usize;
+
+    for y in 0..glyph.height as usize {
+        for x in 0..glyph.width as usize {
+            if glyph.raster[y * glyph.row_size() + x / 8] & (0x80 >> (x % 8)) != 0 {
+                let x = glyph.off_x as usize + x;
+                out[(top + y) * row_size + x / 8] |= 0x80 >> (x % 8);
+            }
+        }
+    }
+
+    out
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// 10x3 cells, where 'A' is a dot on the ninth pixel of the middle row and 'é' is blank.
+    fn font() -> Font {
+        Font {
+            width: 10,
+            height: 3,
+            glyphs: vec![
+                Glyph { char: 'A', width: 1, height: 1, off_x: 8, off_y: 1, raster: vec![0x80] },
+                Glyph { char: 'é', width: 0, height: 0, off_x: 0, off_y: 0, raster: vec![] },
+            ],
+        }
+    }
+
+    #[test]
+    fn psf2_cells_and_table() {
+        let data = psf2(&font());
+
+        assert_eq!(data[..4], PSF2_MAGIC);
+        assert_eq!(data.len(), 32 + 2 * 6 + 5);
+
+        // The dot is on the middle row, on the second byte.
+        assert_eq!(data[32..38], [0, 0, 0, 0x80, 0, 0]);
+        assert_eq!(data[44..], [b'A', 0xFF, 0xC3, 0xA9, 0xFF]);
+    }
+
+    #[test]
+    fn psf1_pads_to_256_glyphs() {
+        let mut font = font();
+        font.width = 8;
+        font.glyphs[0].off_x = 7;
+
+        let data = psf1(&font).unwrap();
+
+        assert_eq!(data[..4], [PSF1_MAGIC[0], PSF1_MAGIC[1], PSF1_MODE_HAS_TABLE, 3]);
+        assert_eq!(data[4..7], [0, 0x01, 0]);
+
+        let table = &data[4 + 256 * 3..];
+        assert_eq!(table.len(), (2 + 2 + 254) * 2);
+        assert_eq!(table[..8], [b'A', 0, 0xFF, 0xFF, 0xE9, 0, 0xFF, 0xFF]);
+    }
+
+    #[test]
+    fn psf1_cells_are_8_pixels_wide() {
+        assert!(psf1(&font()).is_err());
+    }
+}
@@ -0,0 +1,464 @@
+// https://en.wikipedia.org/wiki/ASCII
+// https://en.wikipedia.org/wiki/ANSI_escape_code
+
+
+// BiteMe / ByteMe
+// #[byte_match(\x1B)]
+// #[byte_match([\x1B, \x1B])]
+// #[byte_store()]
+
+use super::{Color, ColorName};
+
+mod parser;
+
+pub use parser::{Action, Parser};
+
+
+pub(crate) const ANSI_ESCAPES: &[&str] = &[
+    "\x1B[30m", // Black
+    "\x1B[31m", // Red
+    "\x1B[32m", // Green
+    "\x1B[33m", // Yellow
+    "\x1B[34m", // Blue
+    "\x1B[35m", // Magenta
+    "\x1B[36m", // Cyan
+    "\x1B[37m", // White
+
+    "\x1B[49m", // Background
+    "\x1B[39m", // Foreground
+];
+
+// Gruvbox material medium dark
+pub(crate) const COLOR_THEME: &[Color] = &[
+    Color::new(0x66, 0x5c, 0x54), // Black
+    Color::new(0xEA, 0x69, 0x62), // Red
+    Color::new(0xA9, 0xB6, 0x65), // Green
+    Color::new(0xD8, 0xA6, 0x57), // Yellow
+    Color::new(0x7D, 0xAE, 0xA3), // Blue
+    Color::new(0xD3, 0x86, 0x9B), // Magenta
+    Color::new(0x89, 0xB4, 0x82), // Cyan
+    Color::new(0xDD, 0xC7, 0xA1), // White
+
+    Color::new(0x29, 0x28, 0x28), // Background
+    Color::new(0xEB, 0xDB, 0xD2), // Foreground
+];
+
+pub(crate) const BRIGHT_COLOR_THEME: &[Color] = &[
+    Color::new(0x92, 0x83, 0x74), // Black
+    Color::new(0xF2, 0x8B, 0x82), // Red
+    Color::new(0xC2, 0xCF, 0x7E), // Green
+    Color::new(0xEC, 0xBB, 0x6C), // Yellow
+    Color::new(0x99, 0xC6, 0xBB), // Blue
+    Color::new(0xE3, 0xA3, 0xB5), // Magenta
+    Color::new(0xA4, 0xCC, 0x9C), // Cyan
+    Color::new(0xF2, 0xE5, 0xBC), // White
+];
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Ansi {
+    /// BEL
+    Bell,
+    /// BS
+    Backspace,
+    /// HT
+    Tab,
+    /// LF
+    LineFeed,
+    /// FF
+    FormFeed,
+    /// CR
+    CarriageReturn,
+    /// ESC
+    Escape(AnsiEscape),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AnsiEscape {
+    // 0x40-0x5F
+
+    /// SS2
+    SingleShiftTwo,
+    /// SS3
+    SingleShiftThree,
+    /// DCS
+    DeviceControlString,
+    /// CSI
+    ControlSequenceIntroducer(AnsiEscapeCSI),
+    /// ST
+    StringTerminator,
+    /// OSC
+    OperatingSystemCommand,
+    /// SOS
+    StartOfString,
+    /// PM
+    PrivacyMessage,
+    /// APC
+    ApplicationProgramCommand,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AnsiEscapeCSI {
+    CursorUp(usize),
+    CursorDown(usize),
+    CursorForward(usize),
+    CursorBack(usize),
+    CursorNextLine(i32),
+    CursorPreviousLine(i32),
+    CursorHorizontalAbsolute(i32),
+    CursorPosition { rows: i32, columns: i32 },
+    EraseInDisplay(EraseBy),
+    EraseInLine(EraseBy),
+    ScrollUp(Option<u32>),
+    ScrollDown(Option<u32>),
+    HorizontalVerticalPosition { rows: i32, columns: i32 },
+    SelectGraphicRendition(GraphicsAttributes),
+    // AUXPortOn
+    // AUXPortOff
+    /// `5` requests the terminal status, `6` the cursor position.
+    DeviceStatusReport(usize),
+
+    SaveCurrentCursorPosition,
+    RestoreSavedCursorPosition,
+
+    /// `?n h`, for example `?25h` to show the cursor.
+    PrivateModeSet(usize),
+    /// `?n l`, for example `?25l` to hide the cursor.
+    PrivateModeReset(usize),
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum EraseBy {
+    #[default]
+    ClearToEnd,
+    ClearToStart,
+    ClearAll,
+}
+
+impl EraseBy {
+    fn from_param(value: Option<usize>) -> Option<Self> {
+        match value.unwrap_or_default() {
+            0 => Some(Self::ClearToEnd),
+            1 => Some(Self::ClearToStart),
+            // 3 also clears the scrollback, which is treated the same.
+            2 | 3 => Some(Self::ClearAll),
+            _ => None,
+        }
+    }
+}
+
+/// Changes made by a Select Graphic Rendition sequence. `None` leaves the attribute as it is.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct GraphicsAttributes {
+    /// Reset or normal. Applied before every other attribute.
+    pub reset: bool,
+    /// Bold or increased intensity
+    pub bold: Option<bool>,
+    // Faint, decreased intensity, or dim
+    // Italic
+    /// Underline
+    pub underline: Option<bool>,
+    // Slow blink
+    // Rapid blink
+    /// Reverse video or invert
+    pub reverse: Option<bool>,
+    // Conceal or hide
+    // Crossed-out, or strike
+    // Primary (default) font
+    // Alternative font
+    // Fraktur (Gothic)
+    // Proportional spacing
+    // Reveal
+    // Not crossed out
+    pub foreground_color: Option<Color>,
+    pub background_color: Option<Color>,
+    // Disable proportional spacing
+    // Framed
+    // Encircled
+    // Overlined
+    // Neither framed nor encircled
+    // Not overlined
+    // Set underline color
+    // Default underline color
+    // Ideogram underline or right side line
+    // Ideogram double underline, or double line on the right side
+    // Ideogram overline or left side line
+    // Ideogram double overline, or double line on the left side
+    // Ideogram stress marking
+    // No ideogram attributes
+    // Superscript
+    // Subscript
+    // Neither superscript nor subscript
+}
+
+impl GraphicsAttributes {
+    fn parse(params: &[Option<usize>]) -> Self {
+        let mut attr = Self::default();
+        let mut params = params.iter().map(|v| v.unwrap_or_default());
+
+        while let Some(num) = params.next() {
+            match num {
+                0 => attr = Self { reset: true, ..Self::default() },
+                1 => attr.bold = Some(true),
+                // 2 	    Faint, decreased intensity, or dim
+                // 3 	    Italic
+                4 => attr.underline = Some(true),
+                // 5 	    Slow blink
+                // 6 	    Rapid blink
+                7 => attr.reverse = Some(true),
+                // 8 	    Conceal or hide
+                // 9 	    Crossed-out, or strike
+                // 10 	    Primary (default) font
+                // 11–19 	Alternative font
+                // 20 	    Fraktur (Gothic)
+                // 21 	    Doubly underlined; or: not bold
+                22 => attr.bold = Some(false),
+                // 23 	    Neither italic, nor blackletter
+                24 => attr.underline = Some(false),
+                // 25 	    Not blinking
+                // 26 	    Proportional spacing
+                27 => attr.reverse = Some(false),
+                // 28 	    Reveal
+                // 29 	    Not crossed out
+                30..=37 => attr.foreground_color = Some(ColorName::from_u8((num - 30) as u8).color()),
+                38 => attr.foreground_color = parse_extended_color(&mut params).or(attr.foreground_color),
+                39 => attr.foreground_color = Some(ColorName::DefaultForeground.color()),
+                40..=47 => attr.background_color = Some(ColorName::from_u8((num - 40) as u8).color()),
+                48 => attr.background_color = parse_extended_color(&mut params).or(attr.background_color),
+                49 => attr.background_color = Some(ColorName::DefaultBackground.color()),
+                // 50 	    Disable proportional spacing
+                // 51 	    Framed
+                // 52 	    Encircled
+                // 53 	    Overlined
+                // 54 	    Neither framed nor encircled
+                // 55 	    Not overlined
+                // 58 	    Set underline color
+                // 59 	    Default underline color
+                // 60 	    Ideogram underline or right side line
+                // 61 	    Ideogram double underline, or double line on the right side
+                // 62 	    Ideogram overline or left side line
+                // 63 	    Ideogram double overline, or double line on the left side
+                // 64 	    Ideogram stress marking
+                // 65 	    No ideogram attributes
+                // 73 	    Superscript
+                // 74 	    Subscript
+                // 75 	    Neither superscript nor subscript
+                90..=97 => attr.foreground_color = Some(Color::from_ansi_256((num - 90 + 8) as u8)),
+                100..=107 => attr.background_color = Some(Color::from_ansi_256((num - 100 + 8) as u8)),
+
+                _ => ()
+            }
+        }
+
+        attr
+    }
+}
+
+/// Parses the `5;n` or `2;r;g;b` following a 38 or 48.
+///
+/// All of the color's parameters are taken before any is checked, so one which is out of range drops the color
+/// rather than leaving the rest to be read as attributes.
+fn parse_extended_color<I: Iterator<Item = usize>>(params: &mut I) -> Option<Color> {
+    let component = |value: Option<usize>| u8::try_from(value?).ok();
+
+    match params.next()? {
+        5 => component(params.next()).map(Color::from_ansi_256),
+        2 => {
+            let (r, g, b) = (params.next(), params.next(), params.next());
+            Some(Color::new(component(r)?, component(g)?, component(b)?))
+        }
+
+        _ => None,
+    }
+}
+
+impl AnsiEscapeCSI {
+    /// Builds a sequence from its parts. Missing or zero parameters are `None`.
+    fn from_parts(private: Option<char>, params: &[Option<usize>], end_letter: char) -> Option<Self> {
+        let param = |index: usize| params.get(index).copied().flatten();
+
+        match private {
+            None => (),
+
+            Some('?') => return match end_letter {
+                'h' => Some(Self::PrivateModeSet(param(0)?)),
+                'l' => Some(Self::PrivateModeReset(param(0)?)),
+
+                _ => None,
+            },
+
+            Some(_) => return None,
+        }
+
+        match end_letter {
+            'A' => Some(Self::CursorUp(param(0).unwrap_or(1))),
+            'B' => Some(Self::CursorDown(param(0).unwrap_or(1))),
+            'C' => Some(Self::CursorForward(param(0).unwrap_or(1))),
+            'D' => Some(Self::CursorBack(param(0).unwrap_or(1))),
+            'E' => Some(Self::CursorNextLine(param(0).unwrap_or(1) as i32)),
+            'F' => Some(Self::CursorPreviousLine(param(0).unwrap_or(1) as i32)),
+            'G' => Some(Self::CursorHorizontalAbsolute(param(0).unwrap_or(1) as i32)),
+
+            'H' => Some(Self::CursorPosition {
+                rows: param(0).unwrap_or(1) as i32,
+                columns: param(1).unwrap_or(1) as i32,
+            }),
+
+            'J' => Some(Self::EraseInDisplay(EraseBy::from_param(param(0))?)),
+            'K' => Some(Self::EraseInLine(EraseBy::from_param(param(0))?)),
+            'S' => Some(Self::ScrollUp(param(0).map(|v| v as u32))),
+            'T' => Some(Self::ScrollDown(param(0).map(|v| v as u32))),
+
+            'f' => Some(Self::HorizontalVerticalPosition {
+                rows: param(0).unwrap_or(1) as i32,
+                columns: param(1).unwrap_or(1) as i32,
+            }),
+
+            'm' => Some(Self::SelectGraphicRendition(GraphicsAttributes::parse(params))),
+
+            's' => Some(Self::SaveCurrentCursorPosition),
+            'u' => Some(Self::RestoreSavedCursorPosition),
+
+            // 5i  AUX Port On
+            // 4i  AUX Port Off
+            'n' => Some(Self::DeviceStatusReport(param(0)?)),
+
+            _ => None
+        }
+    }
+}
+
+impl From<AnsiEscapeCSI> for Ansi {
+    fn from(value: AnsiEscapeCSI) -> Self {
+        Self::Escape(AnsiEscape::ControlSequenceIntroducer(value))
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(value: &str) -> Option<Ansi> {
+        let mut parser = Parser::new();
+
+        value.chars().find_map(|c| match parser.advance(c)? {
+            Action::Ansi(ansi) => Some(ansi),
+            Action::Print(_) => None,
+        })
+    }
+
+    #[test]
+    fn test_name() {
+        assert_eq!(parse("\x1B[30m"), Some(AnsiEscapeCSI::SelectGraphicRendition(GraphicsAttributes { foreground_color: Some(ColorName::Black.color()), ..Default::default() }).into()));
+    }
+
+    #[test]
+    fn multiple_graphic_params() {
+        assert_eq!(parse("\x1B[31;42m"), Some(AnsiEscapeCSI::SelectGraphicRendition(GraphicsAttributes {
+            foreground_color: Some(ColorName::Red.color()),
+            background_color: Some(ColorName::Green.color()),
+            ..Default::default()
+        }).into()));
+    }
+
+    fn sgr(value: &str) -> GraphicsAttributes {
+        match parse(value) {
+            Some(Ansi::Escape(AnsiEscape::ControlSequenceIntroducer(AnsiEscapeCSI::SelectGraphicRendition(attr)))) => attr,
+            v => panic!("expected SGR, got {v:?}"),
+        }
+    }
+
+    #[test]
+    fn graphic_modes() {
+        let attr = sgr("\x1B[1;4;7m");
+        assert_eq!((attr.bold, attr.underline, attr.reverse), (Some(true), Some(true), Some(true)));
+
+        let attr = sgr("\x1B[22;24;27m");
+        assert_eq!((attr.bold, attr.underline, attr.reverse), (Some(false), Some(false), Some(false)));
+    }
+
+    #[test]
+    fn reset_discards_earlier_attributes() {
+        assert_eq!(sgr("\x1B[m"), GraphicsAttributes { reset: true, ..Default::default() });
+        assert_eq!(sgr("\x1B[31;0;1m"), GraphicsAttributes { reset: true, bold: Some(true), ..Default::default() });
+    }
+
+    #[test]
+    fn bright_colors() {
+        assert_eq!(sgr("\x1B[91m").foreground_color, Some(Color::from_ansi_256(9)));
+        assert_eq!(sgr("\x1B[107m").background_color, Some(Color::from_ansi_256(15)));
+    }
+
+    #[test]
+    fn extended_colors() {
+        assert_eq!(sgr("\x1B[38;5;196m").foreground_color, Some(Color::new(255, 0, 0)));
+        assert_eq!(sgr("\x1B[48;5;232m").background_color, Some(Color::new(8, 8, 8)));
+        assert_eq!(sgr("\x1B[38;2;1;2;3;48;2;0;0;0m"), GraphicsAttributes {
+            foreground_color: Some(Color::new(1, 2, 3)),
+            background_color: Some(Color::new(0, 0, 0)),
+            ..Default::default()
+        });
+    }
+
+    #[test]
+    fn invalid_extended_colors_are_skipped_whole() {
+        // The 0s after the out of range red are part of the color, not resets.
+        assert_eq!(sgr("\x1B[1;38;2;300;0;0m"), GraphicsAttributes { bold: Some(true), ..Default::default() });
+        assert_eq!(sgr("\x1B[4;48;5;256;7m"), GraphicsAttributes {
+            underline: Some(true),
+            reverse: Some(true),
+            ..Default::default()
+        });
+    }
+
+    #[test]
+    fn cursor_movement() {
+        assert_eq!(parse("\x1B[A"), Some(AnsiEscapeCSI::CursorUp(1).into()));
+        assert_eq!(parse("\x1B[3E"), Some(AnsiEscapeCSI::CursorNextLine(3).into()));
+        assert_eq!(parse("\x1B[F"), Some(AnsiEscapeCSI::CursorPreviousLine(1).into()));
+        assert_eq!(parse("\x1B[12G"), Some(AnsiEscapeCSI::CursorHorizontalAbsolute(12).into()));
+    }
+
+    #[test]
+    fn cursor_position() {
+        assert_eq!(parse("\x1B[H"), Some(AnsiEscapeCSI::CursorPosition { rows: 1, columns: 1 }.into()));
+        assert_eq!(parse("\x1B[5;10H"), Some(AnsiEscapeCSI::CursorPosition { rows: 5, columns: 10 }.into()));
+        assert_eq!(parse("\x1B[;7H"), Some(AnsiEscapeCSI::CursorPosition { rows: 1, columns: 7 }.into()));
+        assert_eq!(parse("\x1B[2;3f"), Some(AnsiEscapeCSI::HorizontalVerticalPosition { rows: 2, columns: 3 }.into()));
+    }
+
+    #[test]
+    fn erase() {
+        assert_eq!(parse("\x1B[J"), Some(AnsiEscapeCSI::EraseInDisplay(EraseBy::ClearToEnd).into()));
+        assert_eq!(parse("\x1B[2J"), Some(AnsiEscapeCSI::EraseInDisplay(EraseBy::ClearAll).into()));
+        assert_eq!(parse("\x1B[1K"), Some(AnsiEscapeCSI::EraseInLine(EraseBy::ClearToStart).into()));
+        assert_eq!(parse("\x1B[4K"), None);
+    }
+
+    #[test]
+    fn scroll() {
+        assert_eq!(parse("\x1B[S"), Some(AnsiEscapeCSI::ScrollUp(None).into()));
+        assert_eq!(parse("\x1B[4T"), Some(AnsiEscapeCSI::ScrollDown(Some(4)).into()));
+    }
+
+    #[test]
+    fn save_restore_and_status() {
+        assert_eq!(parse("\x1B[s"), Some(AnsiEscapeCSI::SaveCurrentCursorPosition.into()));
+        assert_eq!(parse("\x1B[u"), Some(AnsiEscapeCSI::RestoreSavedCursorPosition.into()));
+        assert_eq!(parse("\x1B[6n"), Some(AnsiEscapeCSI::DeviceStatusReport(6).into()));
+    }
+
+    #[test]
+    fn private_modes() {
+        assert_eq!(parse("\x1B[?25l"), Some(AnsiEscapeCSI::PrivateModeReset(25).into()));
+        assert_eq!(parse("\x1B[?25h"), Some(AnsiEscapeCSI::PrivateModeSet(25).into()));
+        assert_eq!(parse("\x1B[?25A"), None);
+    }
+
+    #[test]
+    fn invalid() {
+        assert_eq!(parse("\x1B[12"), None);
+        assert_eq!(parse("\x1B[1:2A"), None);
+        assert_eq!(parse("\x1B[1 q"), None);
+    }
+}
\ No newline at end of file
@@ -0,0 +1,372 @@
+// https://vt100.net/emu/dec_ansi_parser
+
+use super::{Ansi, AnsiEscape, AnsiEscapeCSI};
+
+/// Parameters past this are dropped.
+const MAX_PARAMS: usize = 16;
+
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Action {
+    /// A character to display.
+    Print(char),
+    /// A control character or a complete escape sequence.
+    Ansi(Ansi),
+}
+
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+enum State {
+    #[default]
+    Ground,
+    Escape,
+    EscapeIntermediate,
+    CsiEntry,
+    CsiParam,
+    CsiIntermediate,
+    CsiIgnore,
+    /// Inside of a control string. Its contents are discarded and `AnsiEscape` is emitted once it ends.
+    String(StringKind),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum StringKind {
+    DeviceControl,
+    OperatingSystemCommand,
+    StartOfString,
+    PrivacyMessage,
+    ApplicationProgramCommand,
+}
+
+impl StringKind {
+    fn escape(self) -> AnsiEscape {
+        match self {
+            Self::DeviceControl => AnsiEscape::DeviceControlString,
+            Self::OperatingSystemCommand => AnsiEscape::OperatingSystemCommand,
+            Self::StartOfString => AnsiEscape::StartOfString,
+            Self::PrivacyMessage => AnsiEscape::PrivacyMessage,
+            Self::ApplicationProgramCommand => AnsiEscape::ApplicationProgramCommand,
+        }
+    }
+}
+
+
+/// A VT500-style state machine parser. Characters can be fed in any chunking, since
+/// the state of an unfinished sequence is kept between calls to [`Parser::advance`].
+#[derive(Debug, Default)]
+pub struct Parser {
+    state: State,
+
+    private: Option<char>,
+    has_intermediate: bool,
+
+    params: [usize; MAX_PARAMS],
+    /// Amount of parameters started so far.
+    param_count: usize,
+}
+
+impl Parser {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Feeds in the next character, returning an action once one is complete.
+    pub fn advance(&mut self, c: char) -> Option<Action> {
+        // Transitions which apply in every state.
+        match c {
+            // CAN, SUB
+            '\x18' | '\x1A' => {
+                self.state = State::Ground;
+                return None;
+            }
+
+            '\x1B' => return self.end_string(State::Escape),
+            // ST
+            '\u{9C}' => return self.end_string(State::Ground),
+
+            // DCS
+            '\u{90}' => return self.enter(State::String(StringKind::DeviceControl)),
+            // CSI
+            '\u{9B}' => return self.enter(State::CsiEntry),
+            // OSC
+            '\u{9D}' => return self.enter(State::String(StringKind::OperatingSystemCommand)),
+            '\u{98}' => return self.enter(State::String(StringKind::StartOfString)),
+            '\u{9E}' => return self.enter(State::String(StringKind::PrivacyMessage)),
+            '\u{9F}' => return self.enter(State::String(StringKind::ApplicationProgramCommand)),
+
+            // SS2, SS3
+            '\u{8E}' => return self.dispatch(Ansi::Escape(AnsiEscape::SingleShiftTwo)),
+            '\u{8F}' => return self.dispatch(Ansi::Escape(AnsiEscape::SingleShiftThree)),
+
+            // Remaining C1 controls have no meaning here.
+            '\u{80}'..='\u{9F}' => {
+                self.state = State::Ground;
+                return None;
+            }
+
+            _ => (),
+        }
+
+        match self.state {
+            State::Ground => match c {
+                '\0'..='\x1F' => execute(c),
+                '\x7F' => None,
+                _ => Some(Action::Print(c)),
+            }
+
+            State::Escape => match c {
+                '\0'..='\x1F' => execute(c),
+                '\x20'..='\x2F' => {
+                    self.state = State::EscapeIntermediate;
+                    None
+                }
+
+                '[' => self.enter(State::CsiEntry),
+                ']' => self.enter(State::String(StringKind::OperatingSystemCommand)),
+                'P' => self.enter(State::String(StringKind::DeviceControl)),
+                'X' => self.enter(State::String(StringKind::StartOfString)),
+                '^' => self.enter(State::String(StringKind::PrivacyMessage)),
+                '_' => self.enter(State::String(StringKind::ApplicationProgramCommand)),
+
+                '\x30'..='\x7E' => match esc_dispatch(c) {
+                    Some(ansi) => self.dispatch(ansi),
+                    None => self.enter(State::Ground),
+                }
+
+                _ => None,
+            }
+
+            // Character set designations and the like, which aren't supported.
+            State::EscapeIntermediate => match c {
+                '\0'..='\x1F' => execute(c),
+                '\x30'..='\x7E' => self.enter(State::Ground),
+                _ => None,
+            }
+
+            State::CsiEntry | State::CsiParam => match c {
+                '\0'..='\x1F' => execute(c),
+
+                '0'..='9' => {
+                    self.state = State::CsiParam;
+                    self.param_digit(c);
+                    None
+                }
+
+                ';' => {
+                    self.state = State::CsiParam;
+                    self.param_separator();
+                    None
+                }
+
+                '\x3C'..='\x3F' if self.state == State::CsiEntry => {
+                    self.state = State::CsiParam;
+                    self.private = Some(c);
+                    None
+                }
+
+                '\x20'..='\x2F' => {
+                    self.state = State::CsiIntermediate;
+                    self.has_intermediate = true;
+                    None
+                }
+
+                '\x40'..='\x7E' => self.csi_dispatch(c),
+
+                // ':' and private markers after parameters.
+                '\x3A'..='\x3F' => {
+                    self.state = State::CsiIgnore;
+                    None
+                }
+
+                _ => None,
+            }
+
+            State::CsiIntermediate => match c {
+                '\0'..='\x1F' => execute(c),
+                '\x20'..='\x2F' => None,
+                '\x30'..='\x3F' => {
+                    self.state = State::CsiIgnore;
+                    None
+                }
+
+                '\x40'..='\x7E' => self.csi_dispatch(c),
+
+                _ => None,
+            }
+
+            State::CsiIgnore => match c {
+                '\0'..='\x1F' => execute(c),
+                '\x40'..='\x7E' => self.enter(State::Ground),
+                _ => None,
+            }
+
+            State::String(kind) => match c {
+                // xterm also ends an OSC with BEL.
+                '\x07' if kind == StringKind::OperatingSystemCommand => self.end_string(State::Ground),
+                _ => None,
+            }
+        }
+    }
+
+    /// Moves to `state`, emitting the control string being left, if any.
+    fn end_string(&mut self, state: State) -> Option<Action> {
+        let prev = self.state;
+
+        self.enter(state);
+
+        match prev {
+            State::String(kind) => Some(Action::Ansi(Ansi::Escape(kind.escape()))),
+            _ => None,
+        }
+    }
+
+    fn enter(&mut self, state: State) -> Option<Action> {
+        self.state = state;
+
+        self.private = None;
+        self.has_intermediate = false;
+        self.params = [0; MAX_PARAMS];
+        self.param_count = 0;
+
+        None
+    }
+
+    fn dispatch(&mut self, ansi: Ansi) -> Option<Action> {
+        self.enter(State::Ground);
+
+        Some(Action::Ansi(ansi))
+    }
+
+    fn param_digit(&mut self, c: char) {
+        if self.param_count == 0 {
+            self.param_count = 1;
+        }
+
+        if let Some(param) = self.params.get_mut(self.param_count - 1) {
+            *param = param.saturating_mul(10).saturating_add(c as usize - '0' as usize);
+        }
+    }
+
+    fn param_separator(&mut self) {
+        // An empty first parameter still counts.
+        self.param_count = (self.param_count.max(1) + 1).min(MAX_PARAMS + 1);
+    }
+
+    fn csi_dispatch(&mut self, c: char) -> Option<Action> {
+        if self.has_intermediate {
+            return self.enter(State::Ground);
+        }
+
+        // There is always at least one, possibly empty, parameter.
+        let count = self.param_count.clamp(1, MAX_PARAMS);
+
+        let mut params = [None; MAX_PARAMS];
+
+        for (param, &value) in params.iter_mut().zip(&self.params[..count]) {
+            *param = Some(value).filter(|&v| v != 0);
+        }
+
+        match AnsiEscapeCSI::from_parts(self.private, &params[..count], c) {
+            Some(csi) => self.dispatch(csi.into()),
+            None => self.enter(State::Ground),
+        }
+    }
+}
+
+
+fn execute(c: char) -> Option<Action> {
+    let ansi = match c {
+        '\x07' => Ansi::Bell,
+        '\x08' => Ansi::Backspace,
+        '\x09' => Ansi::Tab,
+        // VT is treated the same as LF.
+        '\x0A' | '\x0B' => Ansi::LineFeed,
+        '\x0C' => Ansi::FormFeed,
+        '\x0D' => Ansi::CarriageReturn,
+
+        _ => return None,
+    };
+
+    Some(Action::Ansi(ansi))
+}
+
+fn esc_dispatch(c: char) -> Option<Ansi> {
+    match c {
+        'N' => Some(Ansi::Escape(AnsiEscape::SingleShiftTwo)),
+        'O' => Some(Ansi::Escape(AnsiEscape::SingleShiftThree)),
+        '\\' => Some(Ansi::Escape(AnsiEscape::StringTerminator)),
+
+        // DECSC, DECRC
+        '7' => Some(AnsiEscapeCSI::SaveCurrentCursorPosition.into()),
+        '8' => Some(AnsiEscapeCSI::RestoreSavedCursorPosition.into()),
+
+        _ => None,
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec::Vec;
+
+    use super::*;
+
+    fn actions(parser: &mut Parser, value: &str) -> Vec<Action> {
+        value.chars().filter_map(|c| parser.advance(c)).collect()
+    }
+
+    #[test]
+    fn split_across_writes() {
+        let mut parser = Parser::new();
+
+        assert_eq!(actions(&mut parser, "a\x1B["), [Action::Print('a')]);
+        assert_eq!(actions(&mut parser, "5;1"), []);
+        assert_eq!(actions(&mut parser, "0Hb"), [
+            Action::Ansi(AnsiEscapeCSI::CursorPosition { rows: 5, columns: 10 }.into()),
+            Action::Print('b'),
+        ]);
+    }
+
+    #[test]
+    fn controls_inside_of_sequences() {
+        let mut parser = Parser::new();
+
+        assert_eq!(actions(&mut parser, "\x1B[2\rA"), [
+            Action::Ansi(Ansi::CarriageReturn),
+            Action::Ansi(AnsiEscapeCSI::CursorUp(2).into()),
+        ]);
+    }
+
+    #[test]
+    fn cancelled_sequence() {
+        let mut parser = Parser::new();
+
+        assert_eq!(actions(&mut parser, "\x1B[3\x18A"), [Action::Print('A')]);
+        assert_eq!(actions(&mut parser, "\x1B[3\x1B[B"), [Action::Ansi(AnsiEscapeCSI::CursorDown(1).into())]);
+    }
+
+    #[test]
+    fn control_strings_are_discarded() {
+        let mut parser = Parser::new();
+
+        assert_eq!(actions(&mut parser, "\x1B]0;title\x07x"), [
+            Action::Ansi(Ansi::Escape(AnsiEscape::OperatingSystemCommand)),
+            Action::Print('x'),
+        ]);
+
+        assert_eq!(actions(&mut parser, "\x1BPdata\x1B\\"), [
+            Action::Ansi(Ansi::Escape(AnsiEscape::DeviceControlString)),
+            Action::Ansi(Ansi::Escape(AnsiEscape::StringTerminator)),
+        ]);
+    }
+
+    #[test]
+    fn escapes() {
+        let mut parser = Parser::new();
+
+        assert_eq!(actions(&mut parser, "\x1B7\x1B8\x1B(B"), [
+            Action::Ansi(AnsiEscapeCSI::SaveCurrentCursorPosition.into()),
+            Action::Ansi(AnsiEscapeCSI::RestoreSavedCursorPosition.into()),
+        ]);
+    }
+}
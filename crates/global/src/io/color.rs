@@ -1,6 +1,6 @@
 use core::str::FromStr;
 
-use super::ansi::{COLOR_THEME, ANSI_ESCAPES};
+use super::ansi::{COLOR_THEME, BRIGHT_COLOR_THEME, ANSI_ESCAPES};
 
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -19,6 +19,29 @@ impl Color {
     pub fn to_framebuffer_pixel(self) -> [u8; 3] {
         [self.b, self.g, self.r]
     }
+
+    /// Color of the xterm 256 color palette. The first 16 come from the theme.
+    pub fn from_ansi_256(value: u8) -> Self {
+        match value {
+            0..=7 => COLOR_THEME[value as usize],
+            8..=15 => BRIGHT_COLOR_THEME[value as usize - 8],
+
+            // 6x6x6 color cube
+            16..=231 => {
+                let level = |v: u8| if v == 0 { 0 } else { 55 + v * 40 };
+                let value = value - 16;
+
+                Color::new(level(value / 36), level(value / 6 % 6), level(value % 6))
+            }
+
+            // Grayscale ramp
+            232..=255 => {
+                let gray = 8 + (value - 232) * 10;
+
+                Color::new(gray, gray, gray)
+            }
+        }
+    }
 }
 
 impl FromStr for Color {
@@ -10,7 +10,10 @@ pub mod fixed_size_block;
 use fixed_size_block::FixedSizeBlockAllocator;
 
 pub const HEAP_START: usize = 0x4444_4444_0000;
-pub const HEAP_SIZE: usize = 1024 * 1024; // 1 MB
+/// Mostly taken up by the display. Its back buffer alone is ~14 MB at 2560x1440, so this leaves room for the consoles
+/// next to it. The display sizes what it allocates from this, see `display::double_buffer::BACK_BUFFER_BUDGET` and
+/// `display::SCROLLBACK_BUDGET`.
+pub const HEAP_SIZE: usize = 32 * 1024 * 1024; // 32 MB
 
 #[global_allocator]
 static ALLOCATOR: Locked<FixedSizeBlockAllocator> = Locked::new(FixedSizeBlockAllocator::new());
@@ -24,6 +27,11 @@ pub fn get_allocated() -> usize {
     HEAP_SIZE - ALLOCATOR.lock().remaining.load(Ordering::SeqCst)
 }
 
+/// Like [`get_allocated`], but gives up if the allocator is in use. Safe to call from interrupt handlers.
+pub fn try_get_allocated() -> Option<usize> {
+    Some(HEAP_SIZE - ALLOCATOR.try_lock()?.remaining.load(Ordering::SeqCst))
+}
+
 pub(crate) fn init_heap<M, F>(mapper: &mut M, frame_allocator: &mut F) -> Result<(), MapToError<Size4KiB>>
 where
     M: Mapper<Size4KiB>,
@@ -3,11 +3,21 @@
 //!
 //! Enabled mode does not enable x2APIC hardware, but provides the support necessary to the operating system.
 
+use core::time::Duration;
+
 use pic8259::ChainedPics;
 use spin::{Lazy, Mutex};
 use x2apic::{lapic::{LocalApicBuilder, LocalApic}, ioapic::{IoApic, IrqMode, IrqFlags}};
+use x86_64::instructions::port::Port;
+
+use crate::{time, PHYSICAL_MEM_OFFSET};
 
-use crate::PHYSICAL_MEM_OFFSET;
+/// The PIT counts down at this rate on every machine, which is what the APIC timer is measured against.
+const PIT_FREQUENCY: u64 = 1_193_182;
+/// How long the APIC timer is measured for. Has to fit into the PIT's 16 bit counter, so at most ~54ms.
+const CALIBRATION_TIME: Duration = Duration::from_millis(20);
+/// What the timer counts down from until it's calibrated, and after if that fails.
+const UNCALIBRATED_TIMER_INITIAL: u32 = 10_000_000;
 
 pub static LAPIC: Lazy<Mutex<LocalApic>> = Lazy::new(|| {
     let phys_addr = unsafe { x2apic::lapic::xapic_base() };
@@ -15,6 +25,7 @@ pub static LAPIC: Lazy<Mutex<LocalApic>> = Lazy::new(|| {
 
     let lapic = LocalApicBuilder::new()
         .timer_vector(ApicInterruptIndex::Timer as usize)
+        .timer_initial(UNCALIBRATED_TIMER_INITIAL)
         .error_vector(ApicInterruptIndex::Error as usize)
         .spurious_vector(ApicInterruptIndex::Spurious as usize)
         .set_xapic_base(virt_addr)
@@ -58,11 +69,58 @@ pub fn init() {
     unsafe {
         disable_pic();
         LAPIC.lock().enable();
+        calibrate_timer(&mut LAPIC.lock());
         IOAPIC.lock().init(0);
         x86_64::instructions::interrupts::enable();
     }
 }
 
+/// Make the timer fire [`time::TICKS_PER_SECOND`] times a second, by counting how far it gets while the PIT
+/// measures [`CALIBRATION_TIME`]. Leaves it as it is if the PIT doesn't finish.
+unsafe fn calibrate_timer(lapic: &mut LocalApic) {
+    lapic.set_timer_initial(u32::MAX);
+
+    if !pit_wait(CALIBRATION_TIME) {
+        crate::serial_println!("WARN: The PIT didn't count down, the timer is left uncalibrated");
+        lapic.set_timer_initial(UNCALIBRATED_TIMER_INITIAL);
+        return;
+    }
+
+    let counted = (u32::MAX - lapic.timer_current()) as u64;
+    let per_second = counted * 1000 / CALIBRATION_TIME.as_millis() as u64;
+
+    lapic.set_timer_initial((per_second / time::TICKS_PER_SECOND).clamp(1, u32::MAX as u64) as u32);
+}
+
+/// Wait for `duration` with the PIT's channel 2, the one whose output can be read back.
+/// Gives up and returns false if it doesn't count down within about a second.
+unsafe fn pit_wait(duration: Duration) -> bool {
+    let mut control = Port::<u8>::new(0x61);
+    let mut command = Port::<u8>::new(0x43);
+    let mut channel2 = Port::<u8>::new(0x42);
+
+    let count = (PIT_FREQUENCY * duration.as_micros() as u64 / 1_000_000).min(u16::MAX as u64) as u16;
+
+    // Gate off and the speaker disconnected, then channel 2 as a one shot counting down from `count`.
+    let gate = control.read() & !0b11;
+    control.write(gate);
+    command.write(0b1011_0000);
+    channel2.write(count as u8);
+    channel2.write((count >> 8) as u8);
+
+    // Raising the gate starts it. The output goes high once it's done.
+    control.write(gate | 0b1);
+
+    // Every port read takes around a microsecond.
+    for _ in 0..1_000_000 {
+        if control.read() & 0b10_0000 != 0 {
+            return true;
+        }
+    }
+
+    false
+}
+
 unsafe fn disable_pic() {
     let mut pics = ChainedPics::new(IOAPIC_IRQ_OFFSET, IOAPIC_IRQ_OFFSET + 8);
     pics.initialize();
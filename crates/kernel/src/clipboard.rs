@@ -0,0 +1,32 @@
+//! Text copied by selecting output with the mouse, pasted with a middle click or Ctrl+Shift+V.
+
+use alloc::string::String;
+use spin::Mutex;
+
+static CLIPBOARD: Mutex<String> = Mutex::new(String::new());
+
+/// Replaces what's in the clipboard.
+pub fn set(text: String) {
+    *CLIPBOARD.lock() = text;
+}
+
+pub fn get() -> String {
+    CLIPBOARD.lock().clone()
+}
+
+/// Types the clipboard into the input line. Line breaks become spaces and other control characters are left out,
+/// so pasting never submits a command or sends escape sequences.
+pub fn paste() {
+    let text = get()
+        .chars()
+        .filter_map(|char| match char {
+            '\n' | '\t' => Some(' '),
+            char if char.is_control() => None,
+            char => Some(char),
+        })
+        .collect::<String>();
+
+    if !text.is_empty() {
+        input!("{text}");
+    }
+}
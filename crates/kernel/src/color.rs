@@ -26,11 +26,7 @@ pub struct ColoredStr<'a> {
 
 impl core::fmt::Display for ColoredStr<'_> {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-        // Calling `write` directly causes the escape sequence to be printed out in chunks,
-        // which breaks the framebuffer's ANSI parsing... Thus, we allocate a String containing
-        // the full ANSI sequence, then print it out.
-        let full = alloc::format!("\x1B[3{}m{}\x1B[3{}m", self.fg as u8, self.s, ColorName::DefaultForeground as u8);
-        write!(f, "{full}")
+        write!(f, "\x1B[3{}m{}\x1B[3{}m", self.fg as u8, self.s, ColorName::DefaultForeground as u8)
     }
 }
 
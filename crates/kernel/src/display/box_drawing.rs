@@ -0,0 +1,198 @@
+//! Box drawing (U+2500 to U+257F) and block elements (U+2580 to U+259F). They're drawn to fill the whole
+//! cell, so lines join up with their neighbours whatever the font.
+
+use gbl::io::Color;
+
+use super::gfx::{Rect, Surface};
+
+const LIGHT: u16 = 1;
+const HEAVY: u16 = 2;
+const DOUBLE: u16 = 3;
+
+/// Lines from the middle of the cell to its edges, as a nibble per edge: up, right, down, left.
+/// Dashed lines are drawn solid, arcs as corners and diagonals are left to [`draw_diagonal`].
+const LINES: [u16; 0x80] = [
+    0x0101, 0x0202, 0x1010, 0x2020, 0x0101, 0x0202, 0x1010, 0x2020, // ─━│┃┄┅┆┇
+    0x0101, 0x0202, 0x1010, 0x2020, 0x0110, 0x0210, 0x0120, 0x0220, // ┈┉┊┋┌┍┎┏
+    0x0011, 0x0012, 0x0021, 0x0022, 0x1100, 0x1200, 0x2100, 0x2200, // ┐┑┒┓└┕┖┗
+    0x1001, 0x1002, 0x2001, 0x2002, 0x1110, 0x1210, 0x2110, 0x1120, // ┘┙┚┛├┝┞┟
+    0x2120, 0x2210, 0x1220, 0x2220, 0x1011, 0x1012, 0x2011, 0x1021, // ┠┡┢┣┤┥┦┧
+    0x2021, 0x2012, 0x1022, 0x2022, 0x0111, 0x0112, 0x0211, 0x0212, // ┨┩┪┫┬┭┮┯
+    0x0121, 0x0122, 0x0221, 0x0222, 0x1101, 0x1102, 0x1201, 0x1202, // ┰┱┲┳┴┵┶┷
+    0x2101, 0x2102, 0x2201, 0x2202, 0x1111, 0x1112, 0x1211, 0x1212, // ┸┹┺┻┼┽┾┿
+    0x2111, 0x1121, 0x2121, 0x2112, 0x2211, 0x1122, 0x1221, 0x2212, // ╀╁╂╃╄╅╆╇
+    0x1222, 0x2122, 0x2221, 0x2222, 0x0101, 0x0202, 0x1010, 0x2020, // ╈╉╊╋╌╍╎╏
+    0x0303, 0x3030, 0x0310, 0x0130, 0x0330, 0x0013, 0x0031, 0x0033, // ═║╒╓╔╕╖╗
+    0x1300, 0x3100, 0x3300, 0x1003, 0x3001, 0x3003, 0x1310, 0x3130, // ╘╙╚╛╜╝╞╟
+    0x3330, 0x1013, 0x3031, 0x3033, 0x0313, 0x0131, 0x0333, 0x1303, // ╠╡╢╣╤╥╦╧
+    0x3101, 0x3303, 0x1313, 0x3131, 0x3333, 0x0110, 0x0011, 0x1001, // ╨╩╪╫╬╭╮╯
+    0x1100, 0x0000, 0x0000, 0x0000, 0x0001, 0x1000, 0x0100, 0x0010, // ╰╱╲╳╴╵╶╷
+    0x0002, 0x2000, 0x0200, 0x0020, 0x0201, 0x1020, 0x0102, 0x2010, // ╸╹╺╻╼╽╾╿
+];
+
+/// Quadrants of U+2596 onwards, from the lowest bit: upper left, upper right, lower left and lower right.
+const QUADRANTS: [u8; 10] = [0b0100, 0b1000, 0b0001, 0b1101, 0b1001, 0b0111, 0b1011, 0b0010, 0b0110, 0b1110];
+
+
+/// Draws `value` over `rect` if it's one of the characters handled here. `thickness` is the width of a light line.
+pub fn draw(surface: &mut Surface, rect: Rect, value: char, thickness: usize, color: Color) -> bool {
+    match value as u32 {
+        0x2571..=0x2573 => draw_diagonal(surface, rect, value as u32 - 0x2570, thickness, color),
+        code @ 0x2500..=0x257F => draw_lines(surface, rect, LINES[code as usize - 0x2500], thickness, color),
+        code @ 0x2580..=0x259F => draw_block(surface, rect, code, color),
+
+        _ => return false,
+    }
+
+    true
+}
+
+fn draw_lines(surface: &mut Surface, rect: Rect, lines: u16, thickness: usize, color: Color) {
+    let [up, right, down, left] = [12, 8, 4, 0].map(|shift| (lines >> shift) & 0xF);
+
+    // Lines stop at the far side of the ones crossing them, or in the middle if there are none.
+    let across_x = if up.max(down) != 0 { up.max(down) } else { left.max(right) };
+    let across_y = if left.max(right) != 0 { left.max(right) } else { up.max(down) };
+
+    let (join_left, join_right) = extent(rect.x, rect.width, across_x, thickness);
+    let (join_top, join_bottom) = extent(rect.y, rect.height, across_y, thickness);
+
+    let right_edge = rect.x + rect.width;
+    let bottom_edge = rect.y + rect.height;
+
+    for (weight, from, to) in [(left, rect.x, join_right), (right, join_left, right_edge)] {
+        for (y, height) in strokes(rect.y, rect.height, weight, thickness) {
+            surface.fill_rect(Rect::new(from, y, to.saturating_sub(from), height), color);
+        }
+    }
+
+    for (weight, from, to) in [(up, rect.y, join_bottom), (down, join_top, bottom_edge)] {
+        for (x, width) in strokes(rect.x, rect.width, weight, thickness) {
+            surface.fill_rect(Rect::new(x, from, width, to.saturating_sub(from)), color);
+        }
+    }
+}
+
+/// The strokes of a line across a cell, as their start and width.
+fn strokes(start: usize, len: usize, weight: u16, thickness: usize) -> impl Iterator<Item = (usize, usize)> {
+    let centered = |width: usize| start + len.saturating_sub(width) / 2;
+
+    let strokes = match weight {
+        LIGHT => [Some((centered(thickness), thickness)), None],
+        HEAVY => [Some((centered(thickness * 2), thickness * 2)), None],
+        DOUBLE => {
+            let first = centered(thickness * 3);
+            [Some((first, thickness)), Some((first + thickness * 2, thickness))]
+        }
+
+        _ => [None, None],
+    };
+
+    strokes.into_iter().flatten()
+}
+
+/// Where the strokes of a line start and end.
+fn extent(start: usize, len: usize, weight: u16, thickness: usize) -> (usize, usize) {
+    strokes(start, len, weight, thickness)
+        .fold(None, |extent, (stroke, width)| match extent {
+            Some((first, _)) => Some((first, stroke + width)),
+            None => Some((stroke, stroke + width)),
+        })
+        .unwrap_or((start + len / 2, start + len / 2))
+}
+
+/// `1` rises to the right, `2` falls to the right and `3` is both.
+fn draw_diagonal(surface: &mut Surface, rect: Rect, kind: u32, thickness: usize, color: Color) {
+    let (left, top) = (rect.x as isize, rect.y as isize);
+    let (right, bottom) = (left + rect.width as isize - 1, top + rect.height as isize - 1);
+
+    // Thicker lines are drawn as several side by side, kept inside of the cell.
+    for offset in 0..thickness as isize {
+        let (from, to) = (left + offset, right - (thickness as isize - 1) + offset);
+
+        if kind & 1 != 0 {
+            surface.line((from, bottom), (to, top), color);
+        }
+
+        if kind & 2 != 0 {
+            surface.line((from, top), (to, bottom), color);
+        }
+    }
+}
+
+fn draw_block(surface: &mut Surface, rect: Rect, code: u32, color: Color) {
+    let Rect { x, y, width, height } = rect;
+    let eighths = |len: usize, amount: u32| len * amount as usize / 8;
+
+    match code {
+        0x2580 => surface.fill_rect(Rect::new(x, y, width, height / 2), color),
+        0x2581..=0x2588 => {
+            let fill = eighths(height, code - 0x2580);
+            surface.fill_rect(Rect::new(x, y + height - fill, width, fill), color);
+        }
+        0x2589..=0x258F => surface.fill_rect(Rect::new(x, y, eighths(width, 0x2590 - code), height), color),
+        0x2590 => surface.fill_rect(Rect::new(x + width / 2, y, width - width / 2, height), color),
+
+        // Shades are blended, as a pattern would be too fine to make out.
+        0x2591..=0x2593 => surface.blend_rect(rect, color, (code - 0x2590) as u8 * 64),
+
+        0x2594 => surface.fill_rect(Rect::new(x, y, width, eighths(height, 1)), color),
+        0x2595 => {
+            let fill = eighths(width, 1);
+            surface.fill_rect(Rect::new(x + width - fill, y, fill, height), color);
+        }
+
+        _ => {
+            let quadrants = QUADRANTS[(code - 0x2596) as usize];
+            let (half_width, half_height) = (width / 2, height / 2);
+
+            for (bit, (qx, qy)) in [(0, 0), (1, 0), (0, 1), (1, 1)].into_iter().enumerate() {
+                if quadrants & (1 << bit) == 0 {
+                    continue;
+                }
+
+                let (left, top) = (x + qx * half_width, y + qy * half_height);
+                let quadrant_width = if qx == 0 { half_width } else { width - half_width };
+                let quadrant_height = if qy == 0 { half_height } else { height - half_height };
+
+                surface.fill_rect(Rect::new(left, top, quadrant_width, quadrant_height), color);
+            }
+        }
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec;
+    use bootloader_api::info::PixelFormat;
+
+    use super::*;
+    use crate::display::pixel::PixelLayout;
+
+    const WHITE: Color = Color::new(0xFF, 0xFF, 0xFF);
+
+    #[test_case]
+    fn corner_has_no_stubs() {
+        let mut pixels = vec![0; 7 * 15 * 4];
+        let mut surface = Surface::new(&mut pixels, 7, 15, 7, PixelLayout::new(PixelFormat::Rgb, 4));
+
+        assert!(draw(&mut surface, Rect::new(0, 0, 7, 15), '┌', 1, WHITE));
+
+        // The horizontal line runs from the vertical one to the right edge, and the vertical one to the bottom.
+        assert_eq!(surface.get_pixel(3, 7), Some(WHITE));
+        assert_eq!(surface.get_pixel(6, 7), Some(WHITE));
+        assert_eq!(surface.get_pixel(3, 14), Some(WHITE));
+        assert_ne!(surface.get_pixel(2, 7), Some(WHITE));
+        assert_ne!(surface.get_pixel(3, 6), Some(WHITE));
+    }
+
+    #[test_case]
+    fn only_handles_its_ranges() {
+        let mut pixels = vec![0; 4];
+        let mut surface = Surface::new(&mut pixels, 1, 1, 1, PixelLayout::new(PixelFormat::Rgb, 4));
+
+        assert!(!draw(&mut surface, Rect::new(0, 0, 1, 1), 'A', 1, WHITE));
+        assert!(draw(&mut surface, Rect::new(0, 0, 1, 1), '█', 1, WHITE));
+    }
+}
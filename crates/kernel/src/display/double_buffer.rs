@@ -0,0 +1,104 @@
+use alloc::vec::Vec;
+
+use crate::allocator::HEAP_SIZE;
+
+use super::{gfx::{Rect, Surface}, pixel::PixelLayout};
+
+/// Heap the back buffer may take up. Screens which need more are drawn to directly, leaving the rest for the consoles.
+const BACK_BUFFER_BUDGET: usize = HEAP_SIZE / 2;
+
+/// Drawing happens in a back buffer in RAM, which is copied into the framebuffer on [`DoubleBuffer::flip`].
+///
+/// Only the dirty area is copied, a row at a time. There's no vsync to wait on, so the flip happens immediately.
+pub struct DoubleBuffer {
+    front: &'static mut [u8],
+    /// `None` if there wasn't enough memory for it, in which case drawing goes straight to the framebuffer.
+    back: Option<Vec<u8>>,
+
+    width: usize,
+    height: usize,
+    /// Pixels per row.
+    stride: usize,
+    layout: PixelLayout,
+
+    dirty: Option<Rect>,
+}
+
+impl DoubleBuffer {
+    pub fn new(front: &'static mut [u8], width: usize, height: usize, stride: usize, layout: PixelLayout) -> Self {
+        let mut back = Vec::new();
+        let fits = front.len() <= BACK_BUFFER_BUDGET && back.try_reserve_exact(front.len()).is_ok();
+
+        let back = fits.then(|| {
+            back.extend_from_slice(front);
+            back
+        });
+
+        if back.is_none() {
+            crate::serial_println!("WARN: No memory for a back buffer, drawing to the framebuffer directly");
+        }
+
+        Self {
+            front,
+            back,
+
+            width,
+            height,
+            stride,
+            layout,
+
+            dirty: None,
+        }
+    }
+
+    /// Runs `f` with a surface over the back buffer. Whatever it draws is copied on the next flip.
+    pub fn draw<R>(&mut self, f: impl FnOnce(&mut Surface) -> R) -> R {
+        let pixels = match &mut self.back {
+            Some(back) => back.as_mut_slice(),
+            None => &mut *self.front,
+        };
+
+        let mut surface = Surface::new(pixels, self.width, self.height, self.stride, self.layout);
+        let value = f(&mut surface);
+
+        if let Some(damage) = surface.damage() {
+            self.mark_dirty(damage);
+        }
+
+        value
+    }
+
+    fn mark_dirty(&mut self, rect: Rect) {
+        self.dirty = Some(match self.dirty {
+            Some(dirty) => dirty.union(rect),
+            None => rect,
+        });
+    }
+
+    /// Copies the dirty area of the back buffer into the framebuffer.
+    pub fn flip(&mut self) {
+        let Some(dirty) = self.dirty.take() else {
+            return;
+        };
+
+        let Some(back) = &self.back else {
+            return;
+        };
+
+        let bytes_per_pixel = self.layout.bytes_per_pixel();
+        let row_size = self.stride * bytes_per_pixel;
+
+        let start = dirty.x * bytes_per_pixel;
+        let end = (dirty.x + dirty.width) * bytes_per_pixel;
+
+        for y in dirty.y..dirty.y + dirty.height {
+            let row = y * row_size;
+
+            if row + end > self.front.len() {
+                break;
+            }
+
+            self.front[row + start..row + end].copy_from_slice(&back[row + start..row + end]);
+        }
+    }
+}
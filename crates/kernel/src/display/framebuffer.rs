@@ -1,17 +1,32 @@
 use core::fmt::Write;
 
-use alloc::{string::String, format};
+use alloc::{string::String, format, sync::Arc, vec::Vec};
 use bootloader_api::info::FrameBufferInfo;
-use common::Dimensions;
-use gbl::io::{LogType, ansi};
+use common::user::LineEdit;
+use gbl::io::{ColorName, LogType, ansi};
 use spin::{Mutex, Once};
 
-use crate::allocator::get_allocated;
+use crate::{allocator::{try_get_allocated, HEAP_SIZE}, font::Font};
 
-use super::ConsoleContainer;
+use super::{
+    ConsoleContainer, Typeface,
+    double_buffer::DoubleBuffer,
+    gfx::Surface,
+    layout::{Layout, Regions},
+    pixel::PixelLayout,
+    pointer::Pointer,
+    status::{Status, StatusBar},
+};
 
 pub static FB_WRITER: Once<Mutex<FrameBufferWriter>> = Once::new();
 
+/// Amount of virtual consoles, switched between with Alt+F1 and onwards.
+pub const CONSOLE_COUNT: usize = 6;
+/// Console which the tracing log is written to.
+pub const LOG_CONSOLE: usize = 1;
+/// Console which mirrors everything sent over the serial port.
+pub const SERIAL_CONSOLE: usize = 2;
+
 pub(super) fn init(buffer: &'static mut [u8], info: FrameBufferInfo) {
     FB_WRITER.call_once(|| FrameBufferWriter::new(buffer, info).into());
 
@@ -26,135 +41,370 @@ pub(super) fn init(buffer: &'static mut [u8], info: FrameBufferInfo) {
 
 
 pub struct FrameBufferWriter {
-    buffer: &'static mut [u8],
-    #[allow(dead_code)]
+    buffer: DoubleBuffer,
     info: FrameBufferInfo,
 
-    console: ConsoleContainer,
+    typeface: Typeface,
+    layout: Layout,
+    regions: Regions,
+
+    status: StatusBar,
+    pointer: Pointer,
+    consoles: Vec<VirtualConsole>,
+    /// Console which is shown on screen.
+    active: usize,
+    /// Console which output is written to. It follows whichever console last submitted a command.
+    output: usize,
+    /// Console which is currently being written to.
+    target: usize,
 }
 
 impl FrameBufferWriter {
     fn new(buffer: &'static mut [u8], info: FrameBufferInfo) -> Self {
+        let typeface = Typeface::default();
+        let layout = Layout::default();
+        let regions = split(layout, &typeface, &info);
+
         let mut fb = FrameBufferWriter {
-            console: ConsoleContainer::new(Dimensions::from((info.width, info.height)), info.bytes_per_pixel),
-            buffer,
+            typeface,
+            layout,
+            regions,
+
+            status: StatusBar::new(regions.status),
+            pointer: Pointer::new(),
+            consoles: (0..CONSOLE_COUNT).map(|_| VirtualConsole::new(regions)).collect(),
+            active: 0,
+            output: 0,
+            target: 0,
+
+            buffer: DoubleBuffer::new(
+                buffer,
+                info.width,
+                info.height,
+                info.stride,
+                PixelLayout::new(info.pixel_format, info.bytes_per_pixel),
+            ),
             info,
         };
 
-        fb.console.clear(fb.buffer);
-        // TODO: console.set_input_type FullCanvas, Row,
-        fb.console.move_cursor(0, 999);
+        fb.fill_background();
+        fb.update_status();
+        fb.present();
 
         fb
     }
 
     pub fn tick(&mut self) {
-        self.console.tick(self.buffer);
+        self.consoles[self.active].console.tick();
+        self.update_status();
+        self.present();
     }
 
-    // TODO: Check string for new line? Move up first then render. Would fix self.buffer overflow
-    fn process_string(&mut self, s: &str) {
-        let mut chars = s.chars().peekable();
-
-        while let Some(&char) = chars.peek() {
-            // TODO: Where to store these string processors?
-            if let Some(ansi) = ansi::try_parse_ansi(&mut chars) {
-                if let Some(ansi) = ansi {
-                    match ansi {
-                        ansi::Ansi::Bell => todo!(),
-                        ansi::Ansi::Backspace => todo!(),
-                        ansi::Ansi::Tab => todo!(),
-                        ansi::Ansi::LineFeed => todo!(),
-                        ansi::Ansi::FormFeed => todo!(),
-                        ansi::Ansi::CarriageReturn => todo!(),
-                        ansi::Ansi::Escape(escape) => match escape {
-                            ansi::AnsiEscape::SingleShiftTwo => todo!(),
-                            ansi::AnsiEscape::SingleShiftThree => todo!(),
-                            ansi::AnsiEscape::DeviceControlString => todo!(),
-                            ansi::AnsiEscape::ControlSequenceIntroducer(csi) => match csi {
-                                ansi::AnsiEscapeCSI::CursorUp(_) => todo!(),
-                                ansi::AnsiEscapeCSI::CursorDown(_) => todo!(),
-                                ansi::AnsiEscapeCSI::CursorForward(amount) => self.console.move_cursor(amount as i32, 0),
-                                ansi::AnsiEscapeCSI::CursorBack(amount) => self.console.move_cursor(-(amount as i32), 0),
-                                ansi::AnsiEscapeCSI::CursorNextLine(_) => todo!(),
-                                ansi::AnsiEscapeCSI::CursorPreviousLine(_) => todo!(),
-                                ansi::AnsiEscapeCSI::CursorHorizontalAbsolute(_) => todo!(),
-                                ansi::AnsiEscapeCSI::CursorPosition { .. } => todo!(),
-                                ansi::AnsiEscapeCSI::EraseInDisplay(_) => todo!(),
-                                ansi::AnsiEscapeCSI::EraseInLine(_) => todo!(),
-                                ansi::AnsiEscapeCSI::ScrollUp(_) => todo!(),
-                                ansi::AnsiEscapeCSI::ScrollDown(_) => todo!(),
-                                ansi::AnsiEscapeCSI::HorizontalVerticalPosition { .. } => todo!(),
-                                ansi::AnsiEscapeCSI::SelectGraphicRendition(sgr) => {
-                                    if let Some(color) = sgr.background_color {
-                                        self.console.text_style.background = color.color();
-                                    }
-
-                                    if let Some(color) = sgr.foreground_color {
-                                        self.console.text_style.foreground = color.color();
-                                    }
-                                }
-                                ansi::AnsiEscapeCSI::SaveCurrentCursorPosition => todo!(),
-                                ansi::AnsiEscapeCSI::RestoreSavedCursorPosition => todo!(),
-                            },
-                            ansi::AnsiEscape::StringTerminator => todo!(),
-                            ansi::AnsiEscape::OperatingSystemCommand => todo!(),
-                            ansi::AnsiEscape::StartOfString => todo!(),
-                            ansi::AnsiEscape::PrivacyMessage => todo!(),
-                            ansi::AnsiEscape::ApplicationProgramCommand => todo!(),
-                        }
-                    }
+    fn update_status(&mut self) {
+        self.status.update(Status {
+            uptime_seconds: crate::time::uptime().as_secs(),
+            // The tick happens in an interrupt, so the allocator can't be waited on.
+            heap_used: try_get_allocated().map(|used| used / 1024),
+            heap_total: HEAP_SIZE / 1024,
+            tasks: crate::task::task_count(),
+            console: self.active,
+        });
+    }
 
-                    continue;
-                } else {
-                    // crate::serial_println!("Invalid ANSI. Skipping Output.");
-                    break;
-                }
-            }
+    pub fn clear(&mut self) {
+        self.consoles[self.output].console.clear();
+        self.present();
+    }
 
-            let _ = chars.next();
+    /// Divides the screen up differently, resizing every console to fit.
+    pub fn set_layout(&mut self, layout: Layout) {
+        self.layout = layout;
+        self.resize();
+    }
 
-            if char == '\n' {
-                match self.console.log_type {
-                    LogType::Output => {
-                        self.console.process_buffer_check(self.buffer);
+    pub fn layout(&self) -> Layout {
+        self.layout
+    }
 
-                        crate::serial_println!("Allocated: {}/1024", get_allocated() / 1024);
-                    }
+    /// Draws with another font, resizing every console to the amount of cells which fit with it.
+    pub fn set_typeface(&mut self, typeface: Typeface) {
+        self.typeface = typeface;
+        self.resize();
+    }
 
-                    LogType::UserInput => {
-                        self.console.clear_cursor_line(self.buffer);
+    pub fn typeface(&self) -> &Typeface {
+        &self.typeface
+    }
 
-                        self.console.log_type = LogType::Output;
+    /// Splits the screen up again and redraws all of it.
+    fn resize(&mut self) {
+        self.regions = split(self.layout, &self.typeface, &self.info);
 
-                        let input = self.console.take_input();
-                        self.write_fmt(format_args!("{}\n", input.into_iter().collect::<String>())).unwrap();
+        for vc in &mut self.consoles {
+            vc.console.resize(self.regions);
+        }
 
-                        self.console.log_type = LogType::UserInput;
-                    }
+        self.status.resize(self.regions.status);
+        self.update_status();
+
+        self.fill_background();
+        self.present();
+    }
+
+    /// Fills the whole framebuffer, including the margins past the last cells.
+    fn fill_background(&mut self) {
+        self.buffer.draw(|surface| {
+            self.pointer.hide(surface);
+            surface.fill_rect(surface.bounds(), ColorName::DefaultBackground.color());
+        });
+    }
+
+    /// Moves the mouse pointer to the pixel at `position`, showing it if it was hidden.
+    pub fn set_pointer(&mut self, position: (usize, usize)) {
+        let pointer = &mut self.pointer;
+
+        self.buffer.draw(|surface| {
+            pointer.hide(surface);
+            pointer.set_position(position);
+            pointer.show(surface);
+        });
+
+        self.buffer.flip();
+    }
+
+    /// The output cell under a pixel, clamped to the output pane, and whether the pixel is inside of the pane.
+    /// `None` if there is no output pane.
+    fn output_cell(&self, (x, y): (usize, usize)) -> Option<((u16, u16), bool)> {
+        let region = self.regions.output;
+
+        if region.is_empty() {
+            return None;
+        }
+
+        let (width, height) = self.typeface.cell_size();
+        let (column, row) = (x / width.max(1), y / height.max(1));
+
+        let columns = region.x as usize..(region.x + region.width) as usize;
+        let rows = region.y as usize..(region.y + region.height) as usize;
+        let inside = columns.contains(&column) && rows.contains(&row);
+
+        let column = column.clamp(columns.start, columns.end - 1) - columns.start;
+        let row = row.clamp(rows.start, rows.end - 1) - rows.start;
+
+        Some(((column as u16, row as u16), inside))
+    }
+
+    /// Starts selecting output from the cell under a pixel. Anywhere outside of the output clears the selection.
+    pub fn start_selection(&mut self, position: (usize, usize)) {
+        let cell = self.output_cell(position);
+        let console = &mut self.consoles[self.active].console;
+
+        match cell {
+            Some(((x, y), true)) => console.start_selection(x, y),
+            _ => console.clear_selection(),
+        }
+
+        self.present();
+    }
+
+    /// Moves the end of the selection to the cell under a pixel, or the closest one to it.
+    pub fn extend_selection(&mut self, position: (usize, usize)) {
+        if let Some(((x, y), _)) = self.output_cell(position) {
+            self.consoles[self.active].console.extend_selection(x, y);
+            self.present();
+        }
+    }
+
+    /// Copies the selected output into the clipboard, if anything is selected.
+    pub fn copy_selection(&mut self) {
+        if let Some(text) = self.consoles[self.active].console.selected_text() {
+            crate::clipboard::set(text);
+        }
+    }
+
+    /// Width and height of the screen, in pixels.
+    pub fn size(&self) -> (usize, usize) {
+        (self.info.width, self.info.height)
+    }
+
+    /// Shows another console, redrawing all of it.
+    pub fn switch_console(&mut self, index: usize) {
+        if index < self.consoles.len() && index != self.active {
+            self.active = index;
+            self.consoles[index].console.redraw();
+            self.update_status();
+
+            self.present();
+        }
+    }
+
+    /// Writes to a specific console, rather than the one `type_of` usually goes to.
+    fn write_to(&mut self, index: usize, type_of: LogType, args: core::fmt::Arguments) {
+        self.target = index;
+        self.console().log_type = type_of;
+
+        self.write_fmt(args).expect("Failed to write to framebuffer");
+    }
+
+    /// The console being written to.
+    fn console(&mut self) -> &mut ConsoleContainer {
+        &mut self.consoles[self.target].console
+    }
+
+    // TODO: Check string for new line? Move up first then render. Would fix self.buffer overflow
+    fn process_string(&mut self, s: &str) {
+        // New input brings the view back to it, output leaves it where it is.
+        if !s.is_empty() && self.console().log_type == LogType::UserInput {
+            self.console().snap_to_bottom();
+        }
+
+        for char in s.chars() {
+            let vc = &mut self.consoles[self.target];
+
+            match vc.parser.advance(char) {
+                Some(ansi::Action::Print(char)) => match vc.console.log_type {
+                    LogType::Output => vc.console.write_output_char(char),
+                    LogType::UserInput => vc.console.insert_input(char),
                 }
 
-                continue;
+                Some(ansi::Action::Ansi(ansi)) => self.process_ansi(ansi),
+
+                None => (),
             }
+        }
+
+        self.present();
+    }
 
-            // Backspace
-            if char == '\x08' {
-                self.console.handle_backspace(self.buffer);
+    /// Draws what changed on the active console and the status bar into the back buffer, then copies it to the screen.
+    ///
+    /// The pointer is taken off while they're drawn, so it's never drawn over or saved as part of the background.
+    fn present(&mut self) {
+        self.buffer.draw(|surface| self.pointer.hide(surface));
 
-                continue;
+        self.consoles[self.active].console.render(&mut self.buffer, &self.typeface);
+        self.status.render(&mut self.buffer, &self.typeface);
+
+        self.buffer.draw(|surface| self.pointer.show(surface));
+        self.buffer.flip();
+    }
+
+    /// Line feeds are treated as a new line, and submit the input line in `UserInput`.
+    fn process_line_feed(&mut self) {
+        match self.console().log_type {
+            LogType::Output => {
+                self.console().new_line();
             }
 
-            match self.console.log_type {
-                LogType::Output => {
-                    self.console.draw_and_store_glyph_at_end_of_output(char, self.buffer);
-                }
+            LogType::UserInput => {
+                self.console().clear_cursor_line();
 
-                LogType::UserInput => {
-                    self.console.clear_current_cell(self.buffer);
-                    self.console.draw_glyph_in_current_cell(char, self.buffer);
+                self.console().log_type = LogType::Output;
 
-                    self.console.insert_input(char);
-                }
+                let input = self.console().take_input().into_iter().collect::<String>();
+                self.write_fmt(format_args!("> {input}\n")).unwrap();
+
+                self.console().log_type = LogType::UserInput;
+                self.output = self.target;
+
+                crate::task::shell::add_command(input);
+            }
+        }
+    }
+
+    fn process_ansi(&mut self, ansi: ansi::Ansi) {
+        let console = &mut self.consoles[self.target].console;
+
+        match ansi {
+            // There is no speaker to ring.
+            ansi::Ansi::Bell => (),
+            // Moves back in output, erases in input where it's what the keyboard sends for backspace.
+            ansi::Ansi::Backspace => console.handle_backspace(),
+            ansi::Ansi::Tab => match console.log_type {
+                LogType::Output => console.tab(),
+                LogType::UserInput => console.insert_input(' '),
+            }
+            ansi::Ansi::LineFeed => self.process_line_feed(),
+            // VT100 treats form feeds as line feeds.
+            ansi::Ansi::FormFeed => console.line_feed(),
+            ansi::Ansi::CarriageReturn => console.carriage_return(),
+
+            ansi::Ansi::Escape(escape) => match escape {
+                ansi::AnsiEscape::ControlSequenceIntroducer(csi) => match csi {
+                    ansi::AnsiEscapeCSI::CursorUp(amount) => match console.log_type {
+                        LogType::Output => console.move_output_cursor(0, -(amount as i32)),
+                        LogType::UserInput => for _ in 0..amount {
+                            console.history_previous();
+                        }
+                    }
+                    ansi::AnsiEscapeCSI::CursorDown(amount) => match console.log_type {
+                        LogType::Output => console.move_output_cursor(0, amount as i32),
+                        LogType::UserInput => for _ in 0..amount {
+                            console.history_next();
+                        }
+                    }
+                    ansi::AnsiEscapeCSI::CursorForward(amount) => match console.log_type {
+                        LogType::Output => console.move_output_cursor(amount as i32, 0),
+                        LogType::UserInput => for _ in 0..amount {
+                            console.edit_input(LineEdit::Right);
+                        }
+                    }
+                    ansi::AnsiEscapeCSI::CursorBack(amount) => match console.log_type {
+                        LogType::Output => console.move_output_cursor(-(amount as i32), 0),
+                        LogType::UserInput => for _ in 0..amount {
+                            console.edit_input(LineEdit::Left);
+                        }
+                    }
+                    ansi::AnsiEscapeCSI::CursorNextLine(amount) => {
+                        console.carriage_return();
+                        console.move_output_cursor(0, amount.max(1));
+                    }
+                    ansi::AnsiEscapeCSI::CursorPreviousLine(amount) => {
+                        console.carriage_return();
+                        console.move_output_cursor(0, -amount.max(1));
+                    }
+                    ansi::AnsiEscapeCSI::CursorHorizontalAbsolute(column) => {
+                        let y = console.output_cursor().y() as i32;
+                        console.set_output_cursor(column - 1, y);
+                    }
+                    // Both are one based.
+                    ansi::AnsiEscapeCSI::CursorPosition { rows, columns }
+                    | ansi::AnsiEscapeCSI::HorizontalVerticalPosition { rows, columns } => {
+                        console.set_output_cursor(columns - 1, rows - 1);
+                    }
+                    ansi::AnsiEscapeCSI::EraseInDisplay(erase) => console.erase_in_display(erase),
+                    ansi::AnsiEscapeCSI::EraseInLine(erase) => console.erase_in_line(erase),
+                    ansi::AnsiEscapeCSI::ScrollUp(amount) => console.scroll_up(amount.unwrap_or(1) as u16),
+                    ansi::AnsiEscapeCSI::ScrollDown(amount) => console.scroll_down(amount.unwrap_or(1) as u16),
+                    ansi::AnsiEscapeCSI::SelectGraphicRendition(sgr) => console.text_style.apply(&sgr),
+                    ansi::AnsiEscapeCSI::SaveCurrentCursorPosition => console.save_output_cursor(),
+                    ansi::AnsiEscapeCSI::RestoreSavedCursorPosition => console.restore_output_cursor(),
+
+                    // Replies are sent to the host over the serial port.
+                    ansi::AnsiEscapeCSI::DeviceStatusReport(5) => crate::serial_print!("\x1B[0n"),
+                    ansi::AnsiEscapeCSI::DeviceStatusReport(6) => {
+                        let pos = console.output_cursor();
+                        crate::serial_print!("\x1B[{};{}R", pos.y() + 1, pos.x() + 1);
+                    }
+                    ansi::AnsiEscapeCSI::DeviceStatusReport(_) => (),
+
+                    ansi::AnsiEscapeCSI::PrivateModeSet(25) => console.set_cursor_visible(true),
+                    ansi::AnsiEscapeCSI::PrivateModeReset(25) => console.set_cursor_visible(false),
+                    ansi::AnsiEscapeCSI::PrivateModeSet(_)
+                    | ansi::AnsiEscapeCSI::PrivateModeReset(_) => (),
+                },
+
+                // There is only a single character set, so shifting into G2/G3 is a no-op.
+                ansi::AnsiEscape::SingleShiftTwo
+                | ansi::AnsiEscape::SingleShiftThree => (),
+
+                // Control strings carry nothing the console understands, so they are discarded like on a VT100.
+                ansi::AnsiEscape::DeviceControlString
+                | ansi::AnsiEscape::StringTerminator
+                | ansi::AnsiEscape::OperatingSystemCommand
+                | ansi::AnsiEscape::StartOfString
+                | ansi::AnsiEscape::PrivacyMessage
+                | ansi::AnsiEscape::ApplicationProgramCommand => (),
             }
         }
     }
@@ -168,6 +418,196 @@ impl Write for FrameBufferWriter {
     }
 }
 
+/// Divides a screen into panes of cells as large as the typeface's.
+fn split(layout: Layout, typeface: &Typeface, info: &FrameBufferInfo) -> Regions {
+    let (width, height) = typeface.cell_size();
+    let (width, height) = (width.max(1), height.max(1));
+
+    layout.split((info.width / width) as u16, (info.height / height) as u16)
+}
+
+/// A console along with the state of the stream being written to it.
+struct VirtualConsole {
+    console: ConsoleContainer,
+    parser: ansi::Parser,
+}
+
+impl VirtualConsole {
+    fn new(regions: Regions) -> Self {
+        Self {
+            console: ConsoleContainer::new(regions),
+            parser: ansi::Parser::new(),
+        }
+    }
+}
+
+/// Runs `f` on the active console with the framebuffer locked, drawing whatever it changed.
+fn with_console(f: impl FnOnce(&mut ConsoleContainer)) {
+    x86_64::instructions::interrupts::without_interrupts(|| {
+        if let Some(writer) = FB_WRITER.get() {
+            let writer = &mut *writer.lock();
+
+            f(&mut writer.consoles[writer.active].console);
+            writer.present();
+        }
+    })
+}
+
+/// Like [`with_console`], for edits to the input line. Returns the view to the newest output first.
+fn with_input(f: impl FnOnce(&mut ConsoleContainer)) {
+    with_console(|console| {
+        console.snap_to_bottom();
+        f(console);
+    });
+}
+
+/// Applies an edit to the input line.
+pub fn edit_input(edit: LineEdit) {
+    with_input(|console| console.edit_input(edit));
+}
+
+pub fn history_previous() {
+    with_input(ConsoleContainer::history_previous);
+}
+
+pub fn history_next() {
+    with_input(ConsoleContainer::history_next);
+}
+
+pub fn reverse_search() {
+    with_input(ConsoleContainer::reverse_search);
+}
+
+pub fn cancel_search() {
+    with_input(ConsoleContainer::cancel_search);
+}
+
+/// Scrolls the view back (positive) or forward (negative) by `lines`.
+pub fn scroll_view(lines: isize) {
+    with_console(|console| console.scroll_view(lines));
+}
+
+/// Scrolls the view back (positive) or forward (negative) by `pages`.
+pub fn scroll_view_pages(pages: isize) {
+    with_console(|console| console.scroll_view(pages * console.page_lines()));
+}
+
+/// Draws onto the screen outside of the console. The console draws over it wherever its cells change.
+pub fn with_surface(f: impl FnOnce(&mut Surface)) {
+    x86_64::instructions::interrupts::without_interrupts(|| {
+        if let Some(writer) = FB_WRITER.get() {
+            let writer = &mut *writer.lock();
+            let pointer = &mut writer.pointer;
+
+            writer.buffer.draw(|surface| {
+                pointer.hide(surface);
+                f(surface);
+                pointer.show(surface);
+            });
+            writer.buffer.flip();
+        }
+    })
+}
+
+/// Moves the mouse pointer to the pixel at `position`.
+pub fn move_pointer(position: (usize, usize)) {
+    x86_64::instructions::interrupts::without_interrupts(|| {
+        if let Some(writer) = FB_WRITER.get() {
+            writer.lock().set_pointer(position);
+        }
+    })
+}
+
+/// Starts selecting output from the pixel at `position`.
+pub fn start_selection(position: (usize, usize)) {
+    x86_64::instructions::interrupts::without_interrupts(|| {
+        if let Some(writer) = FB_WRITER.get() {
+            writer.lock().start_selection(position);
+        }
+    })
+}
+
+/// Drags the selection to the pixel at `position`.
+pub fn extend_selection(position: (usize, usize)) {
+    x86_64::instructions::interrupts::without_interrupts(|| {
+        if let Some(writer) = FB_WRITER.get() {
+            writer.lock().extend_selection(position);
+        }
+    })
+}
+
+/// Copies the selected output into the clipboard.
+pub fn copy_selection() {
+    x86_64::instructions::interrupts::without_interrupts(|| {
+        if let Some(writer) = FB_WRITER.get() {
+            writer.lock().copy_selection();
+        }
+    })
+}
+
+/// Width and height of the screen in pixels, or `None` if there's no framebuffer.
+pub fn screen_size() -> Option<(usize, usize)> {
+    x86_64::instructions::interrupts::without_interrupts(|| {
+        FB_WRITER.get().map(|writer| writer.lock().size())
+    })
+}
+
+/// Draws every console with `font`, scaled up by `scale`.
+pub fn set_font(font: Arc<dyn Font>, scale: u16) {
+    x86_64::instructions::interrupts::without_interrupts(|| {
+        if let Some(writer) = FB_WRITER.get() {
+            writer.lock().set_typeface(Typeface::new(font, scale));
+        }
+    })
+}
+
+/// Clears every line of output.
+pub fn clear() {
+    x86_64::instructions::interrupts::without_interrupts(|| {
+        if let Some(writer) = FB_WRITER.get() {
+            writer.lock().clear();
+        }
+    })
+}
+
+/// Shows the console at `index`, counting from zero.
+pub fn switch_console(index: usize) {
+    x86_64::instructions::interrupts::without_interrupts(|| {
+        if let Some(writer) = FB_WRITER.get() {
+            writer.lock().switch_console(index);
+        }
+    })
+}
+
+/// Writes to the console at `index`, and to serial.
+pub(crate) fn _print_to(index: usize, args: core::fmt::Arguments) {
+    x86_64::instructions::interrupts::without_interrupts(|| {
+        crate::serial::_print(args);
+
+        if let Some(writer) = FB_WRITER.get() {
+            writer.lock().write_to(index, LogType::Output, args);
+        }
+    })
+}
+
+/// Like `println!`, but onto [`LOG_CONSOLE`].
+#[macro_export]
+macro_rules! log_println {
+    ()            => ($crate::display::framebuffer::_print_to($crate::display::framebuffer::LOG_CONSOLE, format_args!("\n")));
+    ($($arg:tt)*) => ($crate::display::framebuffer::_print_to(
+        $crate::display::framebuffer::LOG_CONSOLE,
+        format_args!("{}\n", format_args!($($arg)*)),
+    ));
+}
+
+/// Copies serial output onto [`SERIAL_CONSOLE`]. Anything sent while the framebuffer is locked is skipped,
+/// since that's the framebuffer itself logging.
+pub(crate) fn mirror_serial(args: core::fmt::Arguments) {
+    if let Some(mut writer) = FB_WRITER.get().and_then(Mutex::try_lock) {
+        writer.write_to(SERIAL_CONSOLE, LogType::Output, args);
+    }
+}
+
 pub(crate) fn _print(type_of: LogType, args: core::fmt::Arguments) {
     use x86_64::instructions::interrupts;
 
@@ -176,9 +616,14 @@ pub(crate) fn _print(type_of: LogType, args: core::fmt::Arguments) {
 
         if let Some(writer) = FB_WRITER.get() {
             let mut writer = writer.lock();
-            writer.console.log_type = type_of;
 
-            writer.write_fmt(args).expect("Failed to write to framebuffer");
+            // Typing goes to the console on screen, output to the one the shell is using.
+            let index = match type_of {
+                LogType::Output => writer.output,
+                LogType::UserInput => writer.active,
+            };
+
+            writer.write_to(index, type_of, args);
         } else if cfg!(debug_assertions) {
             crate::serial_println!("WARN: Framebuffer has not been initialized");
         }
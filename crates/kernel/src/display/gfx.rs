@@ -0,0 +1,366 @@
+//! Drawing primitives over a [`Surface`]. Everything is clipped to the surface.
+
+use gbl::io::Color;
+
+use super::pixel::PixelLayout;
+
+
+/// An area of a surface, in pixels.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Rect {
+    pub x: usize,
+    pub y: usize,
+    pub width: usize,
+    pub height: usize,
+}
+
+impl Rect {
+    pub fn new(x: usize, y: usize, width: usize, height: usize) -> Self {
+        Self { x, y, width, height }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.width == 0 || self.height == 0
+    }
+
+    /// The smallest rect containing both.
+    pub fn union(self, other: Rect) -> Rect {
+        if self.is_empty() {
+            return other;
+        } else if other.is_empty() {
+            return self;
+        }
+
+        let x = self.x.min(other.x);
+        let y = self.y.min(other.y);
+
+        Rect {
+            x,
+            y,
+            width: (self.x + self.width).max(other.x + other.width) - x,
+            height: (self.y + self.height).max(other.y + other.height) - y,
+        }
+    }
+
+    /// The part of both rects which overlaps.
+    pub fn intersect(self, other: Rect) -> Rect {
+        let x = self.x.max(other.x);
+        let y = self.y.max(other.y);
+
+        let right = (self.x + self.width).min(other.x + other.width);
+        let bottom = (self.y + self.height).min(other.y + other.height);
+
+        Rect::new(x, y, right.saturating_sub(x), bottom.saturating_sub(y))
+    }
+}
+
+
+/// An image to blit, row by row.
+pub struct Bitmap<'a> {
+    pub width: usize,
+    pub height: usize,
+    pub pixels: &'a [Color],
+}
+
+
+/// Pixels laid out like a framebuffer. Keeps track of the area which has been drawn to.
+pub struct Surface<'a> {
+    pixels: &'a mut [u8],
+
+    width: usize,
+    height: usize,
+    /// Pixels per row, which may be more than `width`.
+    stride: usize,
+    layout: PixelLayout,
+
+    damage: Option<Rect>,
+}
+
+impl<'a> Surface<'a> {
+    pub fn new(pixels: &'a mut [u8], width: usize, height: usize, stride: usize, layout: PixelLayout) -> Self {
+        // Rows which don't fit in the buffer can't be drawn to.
+        let height = height.min(pixels.len() / (stride * layout.bytes_per_pixel()).max(1));
+
+        Self {
+            pixels,
+
+            width: width.min(stride),
+            height,
+            stride,
+            layout,
+
+            damage: None,
+        }
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn bounds(&self) -> Rect {
+        Rect::new(0, 0, self.width, self.height)
+    }
+
+    /// The area drawn to so far.
+    pub fn damage(&self) -> Option<Rect> {
+        self.damage
+    }
+
+    pub fn get_pixel(&self, x: isize, y: isize) -> Option<Color> {
+        let index = self.index(x, y)?;
+
+        Some(self.layout.decode(&self.pixels[index..index + self.layout.bytes_per_pixel()]))
+    }
+
+    pub fn set_pixel(&mut self, x: isize, y: isize, color: Color) {
+        if let Some(index) = self.index(x, y) {
+            let bytes_per_pixel = self.layout.bytes_per_pixel();
+            let pixel = self.layout.encode(color);
+
+            self.pixels[index..index + bytes_per_pixel].copy_from_slice(&pixel[..bytes_per_pixel]);
+            self.add_damage(Rect::new(x as usize, y as usize, 1, 1));
+        }
+    }
+
+    /// Mixes `color` over a pixel, where an `alpha` of 255 is fully opaque.
+    pub fn blend_pixel(&mut self, x: isize, y: isize, color: Color, alpha: u8) {
+        if let Some(under) = self.get_pixel(x, y) {
+            self.set_pixel(x, y, blend(under, color, alpha));
+        }
+    }
+
+    /// Fills the first row of `rect`, then copies it into the rest, which is much faster than going pixel by pixel.
+    pub fn fill_rect(&mut self, rect: Rect, color: Color) {
+        let rect = rect.intersect(self.bounds());
+
+        if rect.is_empty() {
+            return;
+        }
+
+        let bytes_per_pixel = self.layout.bytes_per_pixel();
+        let row_size = self.stride * bytes_per_pixel;
+        let start = rect.y * row_size + rect.x * bytes_per_pixel;
+        let len = rect.width * bytes_per_pixel;
+
+        let pixel = self.layout.encode(color);
+
+        for (i, byte) in self.pixels[start..start + len].iter_mut().enumerate() {
+            *byte = pixel[i % bytes_per_pixel];
+        }
+
+        for row in 1..rect.height {
+            self.pixels.copy_within(start..start + len, start + row * row_size);
+        }
+
+        self.add_damage(rect);
+    }
+
+    pub fn blend_rect(&mut self, rect: Rect, color: Color, alpha: u8) {
+        let rect = rect.intersect(self.bounds());
+
+        for y in rect.y..rect.y + rect.height {
+            for x in rect.x..rect.x + rect.width {
+                self.blend_pixel(x as isize, y as isize, color, alpha);
+            }
+        }
+    }
+
+    pub fn outline_rect(&mut self, rect: Rect, color: Color) {
+        if rect.is_empty() {
+            return;
+        }
+
+        let right = rect.x + rect.width - 1;
+        let bottom = rect.y + rect.height - 1;
+
+        self.fill_rect(Rect::new(rect.x, rect.y, rect.width, 1), color);
+        self.fill_rect(Rect::new(rect.x, bottom, rect.width, 1), color);
+        self.fill_rect(Rect::new(rect.x, rect.y, 1, rect.height), color);
+        self.fill_rect(Rect::new(right, rect.y, 1, rect.height), color);
+    }
+
+    /// Bresenham's line, including both ends.
+    pub fn line(&mut self, (x0, y0): (isize, isize), (x1, y1): (isize, isize), color: Color) {
+        let dx = (x1 - x0).abs();
+        let dy = -(y1 - y0).abs();
+        let step_x = if x0 < x1 { 1 } else { -1 };
+        let step_y = if y0 < y1 { 1 } else { -1 };
+
+        let (mut x, mut y) = (x0, y0);
+        let mut error = dx + dy;
+
+        loop {
+            self.set_pixel(x, y, color);
+
+            if x == x1 && y == y1 {
+                break;
+            }
+
+            let error2 = error * 2;
+
+            if error2 >= dy {
+                error += dy;
+                x += step_x;
+            }
+
+            if error2 <= dx {
+                error += dx;
+                y += step_y;
+            }
+        }
+    }
+
+    /// Midpoint circle outline.
+    pub fn circle(&mut self, (cx, cy): (isize, isize), radius: isize, color: Color) {
+        self.for_circle_octants(radius, |surface, x, y| {
+            for (px, py) in [(x, y), (y, x), (-y, x), (-x, y), (-x, -y), (-y, -x), (y, -x), (x, -y)] {
+                surface.set_pixel(cx + px, cy + py, color);
+            }
+        });
+    }
+
+    pub fn fill_circle(&mut self, (cx, cy): (isize, isize), radius: isize, color: Color) {
+        self.for_circle_octants(radius, |surface, x, y| {
+            for (half, row) in [(x, y), (x, -y), (y, x), (y, -x)] {
+                surface.fill_span(cx - half, cx + half, cy + row, color);
+            }
+        });
+    }
+
+    /// Copies a bitmap with its top left at `(x, y)`.
+    pub fn blit(&mut self, (x, y): (isize, isize), bitmap: &Bitmap) {
+        for (row, line) in bitmap.pixels.chunks(bitmap.width.max(1)).take(bitmap.height).enumerate() {
+            for (column, &color) in line.iter().enumerate() {
+                self.set_pixel(x + column as isize, y + row as isize, color);
+            }
+        }
+    }
+
+    /// Draws `foreground` where `mask` is set, and `background` elsewhere if there is one.
+    pub fn blit_mask(&mut self, (x, y): (isize, isize), width: usize, mask: &[bool], foreground: Color, background: Option<Color>) {
+        for (row, line) in mask.chunks(width.max(1)).enumerate() {
+            for (column, &set) in line.iter().enumerate() {
+                let color = if set { Some(foreground) } else { background };
+
+                if let Some(color) = color {
+                    self.set_pixel(x + column as isize, y + row as isize, color);
+                }
+            }
+        }
+    }
+
+    /// Calls `f` with each point of the first octant of a circle.
+    fn for_circle_octants(&mut self, radius: isize, mut f: impl FnMut(&mut Self, isize, isize)) {
+        if radius < 0 {
+            return;
+        }
+
+        let (mut x, mut y) = (radius, 0);
+        let mut error = 1 - radius;
+
+        while x >= y {
+            f(self, x, y);
+
+            y += 1;
+
+            if error < 0 {
+                error += 2 * y + 1;
+            } else {
+                x -= 1;
+                error += 2 * (y - x) + 1;
+            }
+        }
+    }
+
+    /// Fills a row from `x0` to `x1`, inclusive.
+    fn fill_span(&mut self, x0: isize, x1: isize, y: isize, color: Color) {
+        if y < 0 || x1 < 0 || x1 < x0 {
+            return;
+        }
+
+        let x0 = x0.max(0) as usize;
+        self.fill_rect(Rect::new(x0, y as usize, x1 as usize + 1 - x0, 1), color);
+    }
+
+    fn index(&self, x: isize, y: isize) -> Option<usize> {
+        if x < 0 || y < 0 || x as usize >= self.width || y as usize >= self.height {
+            return None;
+        }
+
+        Some((y as usize * self.stride + x as usize) * self.layout.bytes_per_pixel())
+    }
+
+    fn add_damage(&mut self, rect: Rect) {
+        self.damage = Some(match self.damage {
+            Some(damage) => damage.union(rect),
+            None => rect,
+        });
+    }
+}
+
+
+/// Mixes `over` on top of `under`, where an `alpha` of 255 is fully `over`.
+pub fn blend(under: Color, over: Color, alpha: u8) -> Color {
+    let mix = |under: u8, over: u8| {
+        ((over as u32 * alpha as u32 + under as u32 * (255 - alpha as u32) + 127) / 255) as u8
+    };
+
+    Color::new(mix(under.r, over.r), mix(under.g, over.g), mix(under.b, over.b))
+}
+
+
+#[cfg(test)]
+mod tests {
+    use alloc::{vec, vec::Vec};
+    use bootloader_api::info::PixelFormat;
+
+    use super::*;
+
+    const WHITE: Color = Color::new(0xFF, 0xFF, 0xFF);
+    const BLACK: Color = Color::new(0, 0, 0);
+
+    fn buffer(width: usize, height: usize) -> Vec<u8> {
+        vec![0; width * height * 4]
+    }
+
+    fn surface(pixels: &mut [u8], width: usize, height: usize) -> Surface<'_> {
+        Surface::new(pixels, width, height, width, PixelLayout::new(PixelFormat::Rgb, 4))
+    }
+
+    #[test_case]
+    fn fill_is_clipped() {
+        let mut pixels = buffer(4, 4);
+        let mut s = surface(&mut pixels, 4, 4);
+
+        s.fill_rect(Rect::new(2, 2, 10, 10), WHITE);
+
+        assert_eq!(s.get_pixel(3, 3), Some(WHITE));
+        assert_eq!(s.get_pixel(1, 1), Some(BLACK));
+        assert_eq!(s.damage(), Some(Rect::new(2, 2, 2, 2)));
+    }
+
+    #[test_case]
+    fn diagonal_line() {
+        let mut pixels = buffer(4, 4);
+        let mut s = surface(&mut pixels, 4, 4);
+
+        s.line((0, 0), (3, 3), WHITE);
+
+        for i in 0..4 {
+            assert_eq!(s.get_pixel(i, i), Some(WHITE));
+        }
+
+        assert_eq!(s.get_pixel(1, 0), Some(BLACK));
+    }
+
+    #[test_case]
+    fn blending() {
+        assert_eq!(blend(BLACK, WHITE, 0), BLACK);
+        assert_eq!(blend(BLACK, WHITE, 255), WHITE);
+        assert_eq!(blend(BLACK, WHITE, 128), Color::new(0x80, 0x80, 0x80));
+    }
+}
@@ -0,0 +1,240 @@
+use core::ops::Range;
+
+use alloc::{vec, vec::Vec};
+
+use super::TextStyle;
+
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Cell {
+    pub char: char,
+    pub style: TextStyle,
+    pub width: CellWidth,
+}
+
+impl Cell {
+    pub fn new(char: char, style: TextStyle) -> Self {
+        Self { char, style, width: CellWidth::Single }
+    }
+
+    /// The left half of a character two cells wide, which has to be followed by [`Cell::spacer`].
+    pub fn wide(char: char, style: TextStyle) -> Self {
+        Self { char, style, width: CellWidth::Wide }
+    }
+
+    pub fn spacer(style: TextStyle) -> Self {
+        Self { char: ' ', style, width: CellWidth::Spacer }
+    }
+}
+
+impl Default for Cell {
+    fn default() -> Self {
+        Self::new(' ', TextStyle::default())
+    }
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum CellWidth {
+    #[default]
+    Single,
+    /// Drawn over this cell and the next.
+    Wide,
+    /// The right half of a wide character, drawn by the cell before it.
+    Spacer,
+}
+
+
+/// The character cells of the screen. Changed cells are marked dirty until they are drawn.
+pub struct Grid {
+    width: u16,
+    height: u16,
+
+    cells: Vec<Cell>,
+    dirty: Vec<bool>,
+    any_dirty: bool,
+}
+
+impl Grid {
+    pub fn new(width: u16, height: u16) -> Self {
+        let size = width as usize * height as usize;
+
+        Self {
+            width,
+            height,
+
+            cells: vec![Cell::default(); size],
+            dirty: vec![false; size],
+            any_dirty: false,
+        }
+    }
+
+    pub fn width(&self) -> u16 {
+        self.width
+    }
+
+    pub fn height(&self) -> u16 {
+        self.height
+    }
+
+    /// Changes the size, keeping the cells which still fit. Everything ends up dirty.
+    pub fn resize(&mut self, width: u16, height: u16) {
+        let mut grid = Grid::new(width, height);
+
+        for y in 0..height.min(self.height) {
+            for x in 0..width.min(self.width) {
+                grid.set(x, y, self.get(x, y));
+            }
+        }
+
+        grid.mark_rows_dirty(0..height);
+        *self = grid;
+    }
+
+    pub fn get(&self, x: u16, y: u16) -> Cell {
+        self.index(x, y).map_or_else(Cell::default, |i| self.cells[i])
+    }
+
+    pub fn row(&self, y: u16) -> &[Cell] {
+        let start = y.min(self.height) as usize * self.width as usize;
+
+        &self.cells[start..(start + self.width as usize).min(self.cells.len())]
+    }
+
+    /// Changes a cell, only marking it dirty if it's actually different.
+    pub fn set(&mut self, x: u16, y: u16, cell: Cell) {
+        if let Some(i) = self.index(x, y) {
+            if self.cells[i] != cell {
+                self.cells[i] = cell;
+                self.dirty[i] = true;
+                self.any_dirty = true;
+            }
+        }
+    }
+
+    pub fn fill(&mut self, y: u16, columns: Range<u16>, cell: Cell) {
+        for x in columns {
+            self.set(x, y, cell);
+        }
+    }
+
+    pub fn fill_rows(&mut self, rows: Range<u16>, cell: Cell) {
+        for y in rows {
+            self.fill(y, 0..self.width, cell);
+        }
+    }
+
+    /// Moves `rows` up by one, blanking the bottom one. Returns the row which was moved out.
+    pub fn scroll_up(&mut self, rows: Range<u16>, blank: Cell) -> Vec<Cell> {
+        if rows.is_empty() {
+            return Vec::new();
+        }
+
+        let removed = self.row(rows.start).to_vec();
+
+        for y in rows.start..rows.end - 1 {
+            for x in 0..self.width {
+                self.set(x, y, self.get(x, y + 1));
+            }
+        }
+
+        self.fill(rows.end - 1, 0..self.width, blank);
+
+        removed
+    }
+
+    /// Moves `rows` down by one, blanking the top one. The bottom row is lost.
+    pub fn scroll_down(&mut self, rows: Range<u16>, blank: Cell) {
+        if rows.is_empty() {
+            return;
+        }
+
+        for y in (rows.start + 1..rows.end).rev() {
+            for x in 0..self.width {
+                self.set(x, y, self.get(x, y - 1));
+            }
+        }
+
+        self.fill(rows.start, 0..self.width, blank);
+    }
+
+    pub fn mark_dirty(&mut self, x: u16, y: u16) {
+        if let Some(i) = self.index(x, y) {
+            self.dirty[i] = true;
+            self.any_dirty = true;
+        }
+    }
+
+    pub fn mark_rows_dirty(&mut self, rows: Range<u16>) {
+        for y in rows {
+            for x in 0..self.width {
+                self.mark_dirty(x, y);
+            }
+        }
+    }
+
+    pub fn is_dirty(&self) -> bool {
+        self.any_dirty
+    }
+
+    /// Clears the dirty flag of a cell, returning whether it was set.
+    pub fn take_dirty(&mut self, x: u16, y: u16) -> bool {
+        match self.index(x, y) {
+            Some(i) => core::mem::take(&mut self.dirty[i]),
+            None => false,
+        }
+    }
+
+    /// Called once every dirty cell has been taken.
+    pub fn clean(&mut self) {
+        self.any_dirty = false;
+    }
+
+    fn index(&self, x: u16, y: u16) -> Option<usize> {
+        (x < self.width && y < self.height).then_some(y as usize * self.width as usize + x as usize)
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn cell(char: char) -> Cell {
+        Cell::new(char, TextStyle::default())
+    }
+
+    #[test_case]
+    fn only_changes_are_dirty() {
+        let mut grid = Grid::new(4, 2);
+
+        grid.set(1, 0, Cell::default());
+        assert!(!grid.is_dirty());
+
+        grid.set(1, 0, cell('a'));
+        assert!(grid.take_dirty(1, 0));
+        assert!(!grid.take_dirty(1, 0));
+        assert!(!grid.take_dirty(0, 0));
+    }
+
+    #[test_case]
+    fn scrolling() {
+        let mut grid = Grid::new(2, 3);
+
+        grid.set(0, 0, cell('a'));
+        grid.set(0, 1, cell('b'));
+        grid.set(0, 2, cell('c'));
+
+        let removed = grid.scroll_up(0..2, Cell::default());
+
+        assert_eq!(removed[0], cell('a'));
+        assert_eq!(grid.get(0, 0), cell('b'));
+        assert_eq!(grid.get(0, 1), Cell::default());
+        assert_eq!(grid.get(0, 2), cell('c'));
+
+        grid.scroll_down(0..3, Cell::default());
+
+        assert_eq!(grid.get(0, 0), Cell::default());
+        assert_eq!(grid.get(0, 1), cell('b'));
+        assert_eq!(grid.get(0, 2), Cell::default());
+    }
+}
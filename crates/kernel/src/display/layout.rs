@@ -0,0 +1,109 @@
+/// An area of the screen, in cells.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct Region {
+    pub x: u16,
+    pub y: u16,
+    pub width: u16,
+    pub height: u16,
+}
+
+impl Region {
+    pub fn new(x: u16, y: u16, width: u16, height: u16) -> Self {
+        Self { x, y, width, height }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.width == 0 || self.height == 0
+    }
+}
+
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum StatusPosition {
+    Top,
+    #[default]
+    Bottom,
+}
+
+/// How the screen is divided into panes. The output gets whatever rows are left over.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Layout {
+    pub status_height: u16,
+    pub status_position: StatusPosition,
+    pub input_height: u16,
+}
+
+impl Default for Layout {
+    fn default() -> Self {
+        Self {
+            status_height: 1,
+            status_position: StatusPosition::default(),
+            input_height: 1,
+        }
+    }
+}
+
+impl Layout {
+    /// Splits a screen of `columns` by `rows` cells. When there isn't enough room the output shrinks first,
+    /// then the status bar, so there's always an input row if there's any row at all.
+    pub fn split(&self, columns: u16, rows: u16) -> Regions {
+        let input_height = self.input_height.min(rows);
+        let status_height = self.status_height.min(rows - input_height);
+        let output_height = rows - input_height - status_height;
+
+        let (status_y, output_y) = match self.status_position {
+            StatusPosition::Top => (0, status_height),
+            StatusPosition::Bottom => (output_height + input_height, 0),
+        };
+
+        Regions {
+            output: Region::new(0, output_y, columns, output_height),
+            status: Region::new(0, status_y, columns, status_height),
+            input: Region::new(0, output_y + output_height, columns, input_height),
+        }
+    }
+}
+
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct Regions {
+    pub output: Region,
+    pub status: Region,
+    pub input: Region,
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test_case]
+    fn status_at_the_bottom() {
+        let layout = Layout { status_height: 1, ..Layout::default() };
+        let regions = layout.split(80, 25);
+
+        assert_eq!(regions.output, Region::new(0, 0, 80, 23));
+        assert_eq!(regions.input, Region::new(0, 23, 80, 1));
+        assert_eq!(regions.status, Region::new(0, 24, 80, 1));
+    }
+
+    #[test_case]
+    fn status_at_the_top() {
+        let layout = Layout { status_height: 1, status_position: StatusPosition::Top, ..Layout::default() };
+        let regions = layout.split(80, 25);
+
+        assert_eq!(regions.status, Region::new(0, 0, 80, 1));
+        assert_eq!(regions.output, Region::new(0, 1, 80, 23));
+        assert_eq!(regions.input, Region::new(0, 24, 80, 1));
+    }
+
+    #[test_case]
+    fn too_small() {
+        let layout = Layout { status_height: 2, ..Layout::default() };
+        let regions = layout.split(80, 2);
+
+        assert!(regions.output.is_empty());
+        assert_eq!(regions.input.height, 1);
+        assert_eq!(regions.status.height, 1);
+    }
+}
@@ -1,16 +1,33 @@
-use alloc::{collections::VecDeque, vec::Vec};
+use core::mem;
+
+use alloc::{collections::VecDeque, string::String, sync::Arc, vec::Vec};
 use bootloader_api::{info::FrameBuffer};
-use common::{Dimensions, user::ConsoleCursor};
-use gbl::io::{LogType, ColorName, Color};
+use common::{Dimensions, Position, user::{ConsoleCursor, History, LineEdit, ReverseSearch}};
+use gbl::io::{LogType, ColorName, Color, ansi::{EraseBy, GraphicsAttributes}};
+
+use crate::{allocator::HEAP_SIZE, font::{self, Font}};
 
-use crate::font::{FONT_HEIGHT, FONT_WIDTH, FONT_SCALE, FONTS};
+use self::{double_buffer::DoubleBuffer, framebuffer::CONSOLE_COUNT, gfx::{Rect, Surface}, grid::{Cell, CellWidth, Grid}, layout::{Region, Regions}};
 
+mod box_drawing;
+mod double_buffer;
 pub mod framebuffer;
+pub mod gfx;
+mod grid;
+pub mod layout;
+mod pixel;
+mod pointer;
+mod status;
 pub mod vga;
 
 
-const FONT_HEIGHT_SCALED: usize = FONT_HEIGHT as usize * FONT_SCALE as usize;
-const FONT_WIDTH_SCALED: usize = FONT_WIDTH as usize * FONT_SCALE as usize;
+const HISTORY_SIZE: usize = 100;
+/// Most rows a console's scrollback keeps.
+const SCROLLBACK_SIZE: usize = 500;
+/// Heap the scrollback of all consoles may take up together, if every row was full.
+const SCROLLBACK_BUDGET: usize = HEAP_SIZE / 4;
+const TAB_WIDTH: u16 = 8;
+const SEARCH_PROMPT: &str = "(reverse-i-search)`";
 
 
 pub fn init(fb: Option<&'static mut FrameBuffer>) {
@@ -32,349 +49,789 @@ pub fn init(fb: Option<&'static mut FrameBuffer>) {
 }
 
 
+/// Rows `columns` wide which fit into a console's share of [`SCROLLBACK_BUDGET`], up to [`SCROLLBACK_SIZE`].
+fn scrollback_rows(columns: u16) -> usize {
+    let row = (columns as usize).max(1) * mem::size_of::<Cell>();
+    (SCROLLBACK_BUDGET / CONSOLE_COUNT / row).min(SCROLLBACK_SIZE)
+}
+
 // TODO: Should this be in the kernel?
 pub struct ConsoleContainer {
-    dimensions: Dimensions<usize>,
-
     pub text_style: TextStyle,
-    bytes_per_pixel: usize,
 
-    // TODO: Private
-    pub(in self) cached_lines: VecDeque<Vec<CacheType>>,
+    output: Grid,
+    output_region: Region,
+    /// Rows which scrolled off the top of the output, oldest first.
+    scrollback: VecDeque<Vec<Cell>>,
+    /// Most rows the scrollback keeps, for rows as wide as the output.
+    scrollback_rows: usize,
+    /// Amount of lines the output is scrolled back by.
+    scroll_offset: usize,
+    selection: Option<Selection>,
 
-    // TODO: Expand upon. For example allow whole container input.
-    input_height: u16,
     pub log_type: LogType,
 
+    /// Where the next output character is written, relative to the top of the output rows.
+    output_pos: Position<u16>,
+    saved_output_pos: Position<u16>,
+
+    input: Grid,
+    input_region: Region,
+
+    /// Position inside of the input pane.
     cursor: ConsoleCursor,
+    cursor_visible: bool,
+    /// Cell the cursor was last drawn in, so it can be drawn over once it moves or blinks.
+    drawn_cursor: Option<(u16, u16)>,
+
+    history: History,
+    search: Option<ReverseSearch>,
 }
 
 impl ConsoleContainer {
-    pub fn new(dimensions: Dimensions<usize>, bytes_per_pixel: usize) -> Self {
+    pub fn new(regions: Regions) -> Self {
         Self {
-            dimensions,
-
             text_style: TextStyle::default(),
-            bytes_per_pixel,
 
-            cached_lines: VecDeque::with_capacity(500),
+            output: Grid::new(regions.output.width, regions.output.height),
+            output_region: regions.output,
+            scrollback: VecDeque::new(),
+            scrollback_rows: scrollback_rows(regions.output.width),
+            scroll_offset: 0,
+            selection: None,
 
-            input_height: 1,
             log_type: LogType::Output,
 
+            output_pos: Position::default(),
+            saved_output_pos: Position::default(),
+
+            input: Grid::new(regions.input.width, regions.input.height),
+            input_region: regions.input,
+
             cursor: ConsoleCursor::default(),
+            cursor_visible: true,
+            drawn_cursor: None,
+
+            history: History::new(HISTORY_SIZE),
+            search: None,
         }
     }
 
     // Cursor Specific
 
-    pub fn move_cursor(&mut self, horiz: i32, vert: i32) {
-        self.cursor.move_me(horiz, vert);
+    pub fn insert_input(&mut self, value: char) {
+        if let Some(search) = &mut self.search {
+            search.push(value, &self.history);
+        } else {
+            self.cursor.insert_input(value, self.input_dimensions());
+        }
+
+        self.redraw_input();
+    }
+
+    pub fn edit_input(&mut self, edit: LineEdit) {
+        match &mut self.search {
+            Some(search) if edit == LineEdit::Backspace => search.pop(&self.history),
 
-        if self.cursor.pos().x() >= self.pixel_width() {
-            self.cursor.set_x(self.pixel_width() - 1);
+            _ => {
+                self.accept_search();
+                self.cursor.edit(edit, self.input_dimensions());
+            }
         }
 
-        if self.cursor.pos().y() >= self.pixel_height() {
-            self.cursor.set_y(self.pixel_height() - 1);
+        self.redraw_input();
+    }
+
+    /// Replaces the input with the previous line in the history.
+    pub fn history_previous(&mut self) {
+        self.accept_search();
+
+        if let Some(line) = self.history.older(self.cursor.input()) {
+            let line = line.to_vec();
+            self.cursor.set_input(line, self.input_dimensions());
         }
+
+        self.redraw_input();
     }
 
-    pub fn insert_input(&mut self, value: char) {
-        self.cursor.insert_input(value, self.screen_dimensions());
+    /// Replaces the input with the next line in the history.
+    pub fn history_next(&mut self) {
+        self.accept_search();
+
+        if let Some(line) = self.history.newer() {
+            self.cursor.set_input(line, self.input_dimensions());
+        }
+
+        self.redraw_input();
     }
 
-    pub fn take_input(&mut self) -> Vec<char> {
-        self.cursor.take_input()
+    /// Starts a reverse search, or jumps to the next older match if one is running.
+    pub fn reverse_search(&mut self) {
+        match &mut self.search {
+            Some(search) => search.next(&self.history),
+            None => self.search = Some(ReverseSearch::default()),
+        }
+
+        self.redraw_input();
     }
 
-    pub fn clear_cursor_line(&mut self, buffer: &mut [u8]) {
-        let mut pos = self.cursor.pos();
+    /// Stops a reverse search, leaving the input as it was before the search.
+    pub fn cancel_search(&mut self) {
+        if self.search.take().is_some() {
+            let input = self.cursor.take_input();
+            self.cursor.set_input(input, self.input_dimensions());
 
-        for i in 0..pos.x() + 1 {
-            pos.set_x(i);
-            self.clear_cell(pos.inner(), buffer);
+            self.redraw_input();
         }
     }
 
-    pub fn handle_backspace(&mut self, buffer: &mut [u8]) {
-        let last_pos = self.cursor.pos().inner();
-
-        match self.log_type {
-            LogType::Output => {
-                self.cached_lines.back_mut().unwrap().pop();
+    /// Replaces the input with the current search match.
+    fn accept_search(&mut self) {
+        if let Some(search) = self.search.take() {
+            if let Some(line) = search.found(&self.history) {
+                let line = line.to_vec();
+                self.cursor.set_input(line, self.input_dimensions());
             }
+        }
+    }
 
-            LogType::UserInput => {
-                self.cursor.backspace();
+    /// Rewrites the whole input row, since an edit may have shifted every character after the cursor.
+    fn redraw_input(&mut self) {
+        let width = self.input.width() as usize;
+
+        let visible = match &self.search {
+            Some(search) => {
+                let mut line = SEARCH_PROMPT.chars().collect::<Vec<_>>();
+                line.extend_from_slice(search.query());
+                line.extend("': ".chars());
+
+                // Keep the cursor on the closing quote of the query.
+                let x = (SEARCH_PROMPT.len() + search.query().len()).min(width.saturating_sub(1));
+                self.cursor.set_x(x as u16);
+
+                if let Some(found) = search.found(&self.history) {
+                    line.extend_from_slice(found);
+                }
+
+                line.truncate(width);
+                line
             }
+
+            None => self.cursor.visible_input(self.input_dimensions()).to_vec(),
+        };
+
+        for x in 0..width as u16 {
+            let value = visible.get(x as usize).copied().unwrap_or(' ');
+            self.input.set(x, 0, Cell::new(value, TextStyle::default()));
         }
+    }
+
+    /// Submits the current input, storing it in the history.
+    pub fn take_input(&mut self) -> Vec<char> {
+        self.accept_search();
+
+        let input = self.cursor.take_input();
+        self.history.push(input.clone());
 
-        self.clear_cell(last_pos, buffer);
+        input
     }
 
-    pub fn clear_current_cell(&mut self, buffer: &mut [u8]) {
-        self.clear_cell(self.cursor.pos().inner(), buffer);
+    pub fn clear_cursor_line(&mut self) {
+        self.input.fill_rows(0..self.input.height(), Cell::default());
     }
 
-    pub fn draw_glyph_in_current_cell(&mut self, value: char, buffer: &mut [u8]) {
-        self.draw_glyph_in_cell(self.cursor.pos().inner(), value, buffer);
+    pub fn handle_backspace(&mut self) {
+        match self.log_type {
+            LogType::Output => self.cursor_back(),
+            LogType::UserInput => self.edit_input(LineEdit::Backspace),
+        }
+    }
+
+    pub fn set_cursor_visible(&mut self, visible: bool) {
+        self.cursor_visible = visible;
     }
 
     //
 
-    pub fn tick(&mut self, buffer: &mut [u8]) {
+    pub fn tick(&mut self) {
         self.cursor.update();
+    }
+
+    // Output Specific
+
+    /// Writes a character at the output position, wrapping onto the next line at the right edge.
+    pub fn write_output_char(&mut self, value: char) {
+        let wide = font::is_wide(value) && self.output.width() >= 2;
+        let width = if wide { 2 } else { 1 };
 
-        if self.cursor.is_displayed() {
-            let curr = self.text_style.foreground;
-            self.text_style.foreground = ColorName::Green.color();
-            self.draw_glyph_in_cell(self.cursor.pos().inner(), '_', buffer);
-            self.text_style.foreground = curr;
+        if self.output_pos.x() + width > self.output.width() {
+            self.new_line();
+        }
+
+        let (x, y) = self.output_pos.inner();
+
+        if wide {
+            self.set_output_cell(x, y, Cell::wide(value, self.text_style));
+            self.set_output_cell(x + 1, y, Cell::spacer(self.text_style));
         } else {
-            self.clear_cell(self.cursor.pos().inner(), buffer);
+            self.set_output_cell(x, y, Cell::new(value, self.text_style));
         }
+
+        self.output_pos.inc_x(width);
     }
 
-    pub fn draw_and_store_glyph_at_end_of_output(&mut self, value: char, buffer: &mut [u8]) {
-        let last_cached_row = self.cached_lines.back_mut().unwrap();
+    /// Moves the output position back a column without erasing, like VT100 does for backspace.
+    pub fn cursor_back(&mut self) {
+        self.move_output_cursor(-1, 0);
+    }
 
-        last_cached_row.push(CacheType::Char(value));
+    /// Sets an output cell, blanking the other half of any wide character it overwrites half of.
+    fn set_output_cell(&mut self, x: u16, y: u16, cell: Cell) {
+        match self.output.get(x, y).width {
+            CellWidth::Wide if cell.width != CellWidth::Wide && x + 1 < self.output.width() => {
+                self.output.set(x + 1, y, self.blank());
+            }
+            CellWidth::Spacer if cell.width != CellWidth::Spacer && x > 0 => self.output.set(x - 1, y, self.blank()),
 
-        let last_line_size = last_cached_row.iter().filter(|c| c.is_char()).count().saturating_sub(1);
-        let buff_len = (self.cached_lines.len() - 1).min(self.pixel_height() as usize - 2);
+            _ => (),
+        }
 
-        self.draw_glyph_in_cell((last_line_size as u16, buff_len as u16), value, buffer);
+        self.output.set(x, y, cell);
     }
 
-    pub fn clear(&mut self, buffer: &mut [u8]) {
-        self.cached_lines.clear();
-        self.cached_lines.push_back(Vec::new());
-
-        let bg = ColorName::DefaultBackground.color().to_framebuffer_pixel();
+    pub fn new_line(&mut self) {
+        self.carriage_return();
+        self.line_feed();
+    }
 
-        // This is faster than using for i in 0..num_subpixels,
-        // since for loops use the `Iterator` trait under the hood,
-        // which uses Clone, rather than Copy.
-        //
-        // This could likely be optimized even further with some
-        // cursed pointer stuff, but it is fast enough as is.
-        let mut i = 0;
-        let num_subpixels = buffer.len();
-        while i < num_subpixels {
-            buffer[i] = bg[i % 3];
+    pub fn carriage_return(&mut self) {
+        self.output_pos.set_x(0);
+    }
 
-            i += 1;
+    /// Moves down a row, scrolling the output once the last row is reached.
+    pub fn line_feed(&mut self) {
+        if self.output_pos.y() + 1 < self.output_rows() {
+            self.output_pos.inc_y(1);
+        } else {
+            self.scroll_up(1);
         }
     }
 
-    fn move_buffer_up(&mut self, buffer: &mut [u8]) {
-        let bg_color = ColorName::DefaultBackground.color().to_framebuffer_pixel();
+    pub fn tab(&mut self) {
+        let next = (self.output_pos.x() / TAB_WIDTH + 1) * TAB_WIDTH;
+        self.output_pos.set_x(next.min(self.output.width().saturating_sub(1)));
+    }
 
-        let buffer_line = self.dim_pixel_width();
+    /// Moves the output position, stopping at the edges of the output rows.
+    pub fn move_output_cursor(&mut self, horiz: i32, vert: i32) {
+        let x = self.output_pos.x() as i32 + horiz;
+        let y = self.output_pos.y() as i32 + vert;
 
-        let font_buffer_line_size = FONT_HEIGHT_SCALED * buffer_line;
+        self.set_output_cursor(x, y);
+    }
 
-        let pix_height = self.pixel_height() as usize;
+    /// Sets the zero based output position, clamped to the output rows.
+    pub fn set_output_cursor(&mut self, x: i32, y: i32) {
+        self.output_pos.set_x(x.min(self.output.width() as i32 - 1).max(0) as u16);
+        self.output_pos.set_y(y.min(self.output_rows() as i32 - 1).max(0) as u16);
+    }
 
-        // TODO: When the buffer moves isn't always correct.
-        let line_widths = self.cached_lines.range(self.cached_lines.len().saturating_sub(pix_height - 1)..)
-            .map(|v| v.iter().filter(|v| v.is_char()).count())
-            .collect::<Vec<_>>();
+    pub fn output_cursor(&self) -> Position<u16> {
+        self.output_pos
+    }
 
-        for i in 1..line_widths.len() {
-            let prev_width = line_widths[i - 1];
-            let curr_width = line_widths[i];
+    pub fn save_output_cursor(&mut self) {
+        self.saved_output_pos = self.output_pos;
+    }
 
-            let prev_line_width = prev_width * FONT_WIDTH_SCALED * self.bytes_per_pixel;
-            let curr_line_width = curr_width * FONT_WIDTH_SCALED * self.bytes_per_pixel;
+    pub fn restore_output_cursor(&mut self) {
+        self.output_pos = self.saved_output_pos;
+    }
 
-            let px = (i - 1) * font_buffer_line_size;
-            let cx = i * font_buffer_line_size;
+    pub fn erase_in_line(&mut self, erase: EraseBy) {
+        let (x, y) = self.output_pos.inner();
 
-            // TODO: Simplify. No need for two for loops.
-            if prev_width != 0 && prev_width > curr_width{
-                for x in 0..self.bytes_per_pixel * prev_width * FONT_HEIGHT_SCALED * FONT_WIDTH_SCALED {
-                    let prev_pos = x % prev_line_width;
+        let cells = match erase {
+            EraseBy::ClearToEnd => x..self.output.width(),
+            EraseBy::ClearToStart => 0..x + 1,
+            EraseBy::ClearAll => 0..self.output.width(),
+        };
 
-                    if prev_pos > curr_line_width {
-                        let index = px
-                            + (x % prev_line_width)
-                            + buffer_line * (x / prev_line_width);
-                        buffer[index] = bg_color[index % 3];
-                    } else {
-                        buffer[
-                            px
-                                // X
-                                + (x % prev_line_width)
-                                // Y
-                                + buffer_line * (x / prev_line_width)
-                        ] =
-                        buffer[
-                            cx
-                                // X
-                                + (x % prev_line_width)
-                                // Y
-                                + buffer_line * (x / prev_line_width)
-                        ];
-                    }
-                }
-            } else {
-                for x in 0..self.bytes_per_pixel * curr_width * FONT_HEIGHT_SCALED * FONT_WIDTH_SCALED {
-                    buffer[
-                        px
-                            // X
-                            + (x % curr_line_width)
-                            // Y
-                            + buffer_line * (x / curr_line_width)
-                    ] =
-                    buffer[
-                        cx
-                            // X
-                            + (x % curr_line_width)
-                            // Y
-                            + buffer_line * (x / curr_line_width)
-                    ];
+        self.output.fill(y, cells, self.blank());
+    }
+
+    pub fn erase_in_display(&mut self, erase: EraseBy) {
+        let y = self.output_pos.y();
+
+        let rows = match erase {
+            EraseBy::ClearToEnd => y + 1..self.output_rows(),
+            EraseBy::ClearToStart => 0..y,
+            EraseBy::ClearAll => 0..self.output_rows(),
+        };
+
+        self.erase_in_line(erase);
+        self.output.fill_rows(rows, self.blank());
+    }
+
+    /// Moves the output up by `amount` rows, adding blank rows at the bottom. Rows pushed off the top go into the scrollback.
+    pub fn scroll_up(&mut self, amount: u16) {
+        for _ in 0..amount.min(self.output_rows()) {
+            let mut row = self.output.scroll_up(0..self.output_rows(), self.blank());
+
+            // Trailing blanks aren't worth keeping around.
+            let len = row.iter().rposition(|&cell| cell != Cell::default()).map_or(0, |i| i + 1);
+            row.truncate(len);
+            row.shrink_to_fit();
+
+            // Make room for the row, giving up the oldest ones rather than running out of memory.
+            while !self.scrollback.is_empty()
+                && (self.scrollback.len() >= self.scrollback_rows || self.scrollback.try_reserve(1).is_err())
+            {
+                self.drop_oldest_row();
+            }
+
+            if self.scrollback.len() < self.scrollback_rows && self.scrollback.try_reserve(1).is_ok() {
+                self.scrollback.push_back(row);
+
+                // Keep showing the same rows while scrolled back.
+                if self.scroll_offset > 0 {
+                    self.scroll_offset = (self.scroll_offset + 1).min(self.scrollback.len());
                 }
             }
         }
+    }
 
-        // TODO: Move buffer based on cached_lines.
-        // let line_offset = buffer_line * 2;
-        // // TODO: Line offset may be incorrect.
+    fn drop_oldest_row(&mut self) {
+        self.scrollback.pop_front();
+        self.scroll_offset = self.scroll_offset.min(self.scrollback.len());
 
-        // // Move Buffer Up
-        // let mut i = 0;
-        // let num_subpixels = self.buffer.len() - (font_buffer_line_size + line_offset);
-        // while i < num_subpixels {
-        //     self.buffer[i + line_offset] = self.buffer[i + font_buffer_line_size + line_offset];
+        // Rows are counted from the oldest one, which is gone now.
+        self.selection = self.selection.and_then(Selection::drop_first_row);
+    }
 
-        //     i += 1;
-        // }
+    /// Moves the output down by `amount` rows, adding blank rows at the top. Rows pushed off the bottom are lost.
+    pub fn scroll_down(&mut self, amount: u16) {
+        for _ in 0..amount.min(self.output_rows()) {
+            self.output.scroll_down(0..self.output_rows(), self.blank());
+        }
+    }
 
-        // Clear last line
-        // TODO: Remove the previous line clear. We should only be clearing the user input one.
-        let mut i = buffer.len() - font_buffer_line_size * 2;
-        let num_subpixels = buffer.len();
-        while i < num_subpixels {
-            buffer[i] = bg_color[i % 3];
+    /// Scrolls the view back (positive) or forward (negative) through the scrollback.
+    pub fn scroll_view(&mut self, lines: isize) {
+        let offset = self.scroll_offset.saturating_add_signed(lines).min(self.scrollback.len());
 
-            i += 1;
+        if offset != self.scroll_offset {
+            self.scroll_offset = offset;
+            self.output.mark_rows_dirty(0..self.output_rows());
         }
     }
 
-    fn process_buffer_check(&mut self, buffer: &mut [u8]) {
-        if self.cached_lines.len() == self.cached_lines.capacity() {
-            self.cached_lines.pop_front();
+    /// Amount of lines a page up/down scrolls by.
+    pub fn page_lines(&self) -> isize {
+        (self.output_rows() as isize - 1).max(1)
+    }
+
+    /// Returns the view to the newest output.
+    pub fn snap_to_bottom(&mut self) {
+        self.scroll_view(-(self.scroll_offset as isize));
+    }
+
+    /// Row shown at the top of the output pane, counting the scrollback and then the output from the oldest row.
+    fn view_top(&self) -> usize {
+        self.scrollback.len() - self.scroll_offset
+    }
+
+    /// A cell of the scrollback or the output, with rows counted like [`ConsoleContainer::view_top`].
+    fn content_cell(&self, row: usize, x: u16) -> Cell {
+        match self.scrollback.get(row) {
+            Some(line) => line.get(x as usize).copied().unwrap_or_default(),
+            None => self.output.get(x, (row - self.scrollback.len()) as u16),
         }
+    }
+
+    /// The output cell shown at a position, which comes from the scrollback while scrolled back.
+    fn displayed_cell(&self, x: u16, y: u16) -> Cell {
+        self.content_cell(self.view_top() + y as usize, x)
+    }
+
+    /// The output cell to draw for a position, and where it starts. The right half of a wide character is drawn by
+    /// drawing all of it, and halves left without their other half are drawn as blanks.
+    fn drawn_cell(&self, x: u16, y: u16) -> (u16, Cell) {
+        let cell = self.displayed_cell(x, y);
+        let width_at = |x: u16| (x < self.output.width()).then(|| self.displayed_cell(x, y).width);
+
+        match cell.width {
+            CellWidth::Single => (x, cell),
+
+            CellWidth::Wide if width_at(x + 1) == Some(CellWidth::Spacer) => (x, cell),
+            CellWidth::Spacer if x > 0 && width_at(x - 1) == Some(CellWidth::Wide) => (x - 1, self.displayed_cell(x - 1, y)),
 
-        if self.cached_lines.len() as u16 + self.input_height >= self.pixel_height() {
-            self.move_buffer_up(buffer);
-        //     self.cursor_pos.set_x(0);
-        // } else {
-        //     self.cursor_pos.set_x(0);
-        //     self.cursor_pos.inc_y(1);
+            _ => (x, Cell::new(' ', cell.style)),
         }
+    }
 
-        self.cached_lines.push_back(Vec::new());
+    /// An erased cell, which keeps the current background.
+    fn blank(&self) -> Cell {
+        Cell::new(' ', self.text_style.erased())
     }
 
-    pub fn clear_cell(&mut self, (sx, sy): (u16, u16), buffer: &mut [u8]) {
-        let cell_x = sx * FONT_SCALE * FONT_WIDTH;
-        let cell_y = sy * FONT_SCALE * FONT_HEIGHT;
+    fn output_rows(&self) -> u16 {
+        self.output.height()
+    }
 
-        for y in 0..FONT_HEIGHT + 1 {
-            for x in 0..FONT_WIDTH {
-                let x = x * FONT_SCALE;
-                let y = y * FONT_SCALE;
+    // Selection
 
-                self.draw_scaled_pixel(self.text_style.background, FONT_SCALE, (cell_x + x, cell_y + y), buffer);
-            }
+    /// Starts selecting from a cell of the output pane, replacing any previous selection.
+    pub fn start_selection(&mut self, x: u16, y: u16) {
+        let cell = (self.view_top() + y as usize, x);
+        self.set_selection(Some(Selection { anchor: cell, head: cell }));
+    }
+
+    /// Moves the end of the selection to a cell of the output pane.
+    pub fn extend_selection(&mut self, x: u16, y: u16) {
+        if let Some(selection) = self.selection {
+            let head = (self.view_top() + y as usize, x);
+            self.set_selection(Some(Selection { head, ..selection }));
         }
     }
 
-    pub fn draw_glyph_in_cell(&mut self, (sx, sy): (u16, u16), char: char, buffer: &mut [u8]) {
-        let glyph = &FONTS[char as usize];
+    pub fn clear_selection(&mut self) {
+        self.set_selection(None);
+    }
 
-        // (0, 0) is at the bottom left of the glyph,
-        // while `glyph.raster` starts at the top left,
-        // so the glyph has to be offset accordingly
-        let cell_offset_y = FONT_SCALE * (FONT_HEIGHT.max(glyph.height) - glyph.height);
+    fn set_selection(&mut self, selection: Option<Selection>) {
+        if selection == self.selection {
+            return;
+        }
+
+        // Redraw the rows which are highlighted, or were.
+        let top = self.view_top();
+
+        for (start, end) in [self.selection, selection].into_iter().flatten().map(Selection::ordered) {
+            let first = start.0.saturating_sub(top).min(self.output_rows() as usize);
+            let last = (end.0 + 1).saturating_sub(top).min(self.output_rows() as usize);
+
+            self.output.mark_rows_dirty(first as u16..last as u16);
+        }
+
+        self.selection = selection;
+    }
 
-        let cell_x = sx * FONT_SCALE * FONT_WIDTH;
-        let cell_y = sy * FONT_SCALE * FONT_HEIGHT;
+    /// The selected output, a line per row without the blanks at the end of each. `None` if nothing is selected.
+    pub fn selected_text(&self) -> Option<String> {
+        let selection = self.selection.filter(|selection| !selection.is_empty())?;
+        let ((start_row, start_x), (end_row, end_x)) = selection.ordered();
 
-        for y in 0..glyph.height {
-            for x in 0..glyph.width {
-                let index = y * glyph.width + x;
-                let fg_pixel = glyph.display[index as usize];
+        let mut text = String::new();
 
-                let x = x * FONT_SCALE;
-                let y = y * FONT_SCALE;
+        for row in start_row..=end_row {
+            let from = if row == start_row { start_x } else { 0 };
+            let to = if row == end_row { end_x + 1 } else { self.output.width() };
 
-                let draw_x = (cell_x + x) as isize + (FONT_SCALE as isize * glyph.off_x);
-                let draw_y = (cell_y + y + cell_offset_y) as isize - (FONT_SCALE as isize * glyph.off_y);
+            let line = (from..to)
+                .map(|x| self.content_cell(row, x))
+                .filter(|cell| cell.width != CellWidth::Spacer)
+                .map(|cell| cell.char)
+                .collect::<String>();
 
-                let color = if fg_pixel { self.text_style.foreground } else { self.text_style.background };
+            text.push_str(line.trim_end());
 
-                self.draw_scaled_pixel(color, FONT_SCALE, (draw_x as u16, draw_y as u16), buffer);
+            if row != end_row {
+                text.push('\n');
             }
         }
+
+        Some(text)
+    }
+
+    /// Marks every cell for drawing, for when the console is shown again.
+    pub fn redraw(&mut self) {
+        self.output.mark_rows_dirty(0..self.output.height());
+        self.input.mark_rows_dirty(0..self.input.height());
+        self.drawn_cursor = None;
     }
 
-    pub fn draw_pixel(&mut self, color: Color, (x, y): (u16, u16), buffer: &mut [u8]) {
-        let pixel_index = (y as usize * self.dim_pixel_width()) + (x as usize * self.bytes_per_pixel);
+    /// Moves the panes, keeping as much of their contents as fits.
+    pub fn resize(&mut self, regions: Regions) {
+        self.snap_to_bottom();
+        self.clear_selection();
 
-        // Prevent Going out of buffer bounds.
-        // TODO: Improve on. We shouldn't cut off pixels.
-        if buffer.len() <= pixel_index + self.bytes_per_pixel {
-            return;
+        // Keep the row being written to on screen, moving the ones above it into the scrollback.
+        let rows = regions.output.height;
+
+        if self.output_pos.y() >= rows {
+            self.scroll_up(self.output_pos.y() + 1 - rows);
         }
 
-        buffer[pixel_index    ] = color.b;
-        buffer[pixel_index + 1] = color.g;
-        buffer[pixel_index + 2] = color.r;
+        self.output.resize(regions.output.width, regions.output.height);
+        self.output_region = regions.output;
 
-        if self.bytes_per_pixel > 3 {
-            for i in 3..self.bytes_per_pixel {
-                buffer[pixel_index + i] = 0xFF;
-            }
+        self.scrollback_rows = scrollback_rows(regions.output.width);
+        while self.scrollback.len() > self.scrollback_rows {
+            self.drop_oldest_row();
         }
+
+        self.input.resize(regions.input.width, regions.input.height);
+        self.input_region = regions.input;
+
+        let (x, y) = self.output_pos.inner();
+        self.set_output_cursor(x as i32, y as i32);
+
+        let (x, y) = self.saved_output_pos.inner();
+        self.saved_output_pos.set_x(x.min(regions.output.width.saturating_sub(1)));
+        self.saved_output_pos.set_y(y.min(rows.saturating_sub(1)));
+
+        let input = self.cursor.take_input();
+        self.cursor.set_input(input, self.input_dimensions());
+
+        self.redraw_input();
+        self.redraw();
     }
 
-    fn draw_scaled_pixel(&mut self, color: Color, scale: u16, (x, y): (u16, u16), buffer: &mut [u8]) {
-        for sy in 0..scale {
-            for sx in 0..scale {
-                self.draw_pixel(color, (x + sx, y + sy), buffer);
+    /// Clears the output and the scrollback.
+    pub fn clear(&mut self) {
+        self.snap_to_bottom();
+        self.clear_selection();
+        self.scrollback.clear();
+        self.output_pos = Position::default();
+
+        self.output.fill_rows(0..self.output_rows(), Cell::default());
+    }
+
+    // Drawing
+
+    /// Draws every dirty cell, along with the cursor.
+    pub fn render(&mut self, buffer: &mut DoubleBuffer, typeface: &Typeface) {
+        let cursor = (self.cursor_visible && self.cursor.is_displayed()).then(|| self.cursor.pos().inner());
+
+        if cursor != self.drawn_cursor {
+            for (x, y) in self.drawn_cursor.into_iter().chain(cursor) {
+                self.input.mark_dirty(x, y);
             }
+
+            self.drawn_cursor = cursor;
         }
+
+        if !self.output.is_dirty() && !self.input.is_dirty() {
+            return;
+        }
+
+        let top = self.view_top();
+        let selection = self.selection.filter(|selection| !selection.is_empty());
+
+        buffer.draw(|surface| {
+            let region = self.output_region;
+
+            for y in 0..self.output.height() {
+                for x in 0..self.output.width() {
+                    if self.output.take_dirty(x, y) {
+                        let (x, mut cell) = self.drawn_cell(x, y);
+
+                        if selection.map_or(false, |selection| selection.contains((top + y as usize, x))) {
+                            cell.style.reverse = !cell.style.reverse;
+                        }
+
+                        typeface.draw_cell(surface, (region.x + x, region.y + y), cell);
+                    }
+                }
+            }
+
+            let region = self.input_region;
+
+            for y in 0..self.input.height() {
+                for x in 0..self.input.width() {
+                    if !self.input.take_dirty(x, y) {
+                        continue;
+                    }
+
+                    let mut cell = self.input.get(x, y);
+
+                    if cursor == Some((x, y)) {
+                        let style = TextStyle {
+                            foreground: ColorName::Green.color(),
+                            background: cell.style.colors().1,
+                            ..TextStyle::default()
+                        };
+
+                        cell = Cell::new('_', style);
+                    }
+
+                    typeface.draw_cell(surface, (region.x + x, region.y + y), cell);
+                }
+            }
+        });
+
+        self.output.clean();
+        self.input.clean();
+    }
+
+    fn input_dimensions(&self) -> Dimensions<u16> {
+        Dimensions::from((self.input.width(), self.input.height()))
     }
+}
 
+/// Output selected with the mouse, running from one cell to another like text does. Cells are a row, counted like
+/// [`ConsoleContainer::view_top`], and a column.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Selection {
+    /// Where the selection was started.
+    anchor: (usize, u16),
+    /// Where it's been dragged to, which may be before the anchor.
+    head: (usize, u16),
+}
 
-    fn dim_pixel_width(&self) -> usize {
-        self.dimensions.width() * self.bytes_per_pixel
+impl Selection {
+    /// The first and last cell, inclusive.
+    fn ordered(self) -> ((usize, u16), (usize, u16)) {
+        (self.anchor.min(self.head), self.anchor.max(self.head))
     }
 
-    fn pixel_width(&self) -> u16 {
-        (self.dimensions.width() / (FONT_WIDTH * FONT_SCALE) as usize) as u16
+    /// A click without a drag selects nothing.
+    fn is_empty(&self) -> bool {
+        self.anchor == self.head
     }
 
-    fn pixel_height(&self) -> u16 {
-        (self.dimensions.height() / (FONT_HEIGHT * FONT_SCALE) as usize) as u16
+    fn contains(&self, cell: (usize, u16)) -> bool {
+        let (start, end) = self.ordered();
+        (start..=end).contains(&cell)
     }
 
-    fn screen_dimensions(&self) -> Dimensions<u16> {
-        Dimensions::from((
-            self.pixel_width(),
-            self.pixel_height(),
-        ))
+    /// Follows the rows up once the first row is dropped. `None` if the selection was on it.
+    fn drop_first_row(self) -> Option<Self> {
+        Some(Self {
+            anchor: (self.anchor.0.checked_sub(1)?, self.anchor.1),
+            head: (self.head.0.checked_sub(1)?, self.head.1),
+        })
     }
 }
 
+/// A font at the scale it's drawn with.
+#[derive(Clone)]
+pub struct Typeface {
+    pub font: Arc<dyn Font>,
+    /// Every pixel of the font is drawn as a square this many pixels wide.
+    pub scale: u16,
+}
+
+impl Default for Typeface {
+    fn default() -> Self {
+        Self {
+            font: Arc::new(font::BuiltinFont),
+            scale: 1,
+        }
+    }
+}
+
+impl Typeface {
+    pub fn new(font: Arc<dyn Font>, scale: u16) -> Self {
+        Self { font, scale: scale.max(1) }
+    }
+
+    /// Width and height of a cell on screen, in pixels.
+    pub fn cell_size(&self) -> (usize, usize) {
+        let (width, height) = self.font.cell_size();
+        let scale = self.scale as usize;
+
+        (width as usize * scale, height as usize * scale)
+    }
+
+    /// Pixels covered by a cell.
+    fn cell_rect(&self, (x, y): (u16, u16)) -> Rect {
+        let (width, height) = self.cell_size();
+
+        Rect::new(x as usize * width, y as usize * height, width, height)
+    }
+
+    /// Draws a cell, along with the one after it if it's [`CellWidth::Wide`].
+    fn draw_cell(&self, surface: &mut Surface, pos: (u16, u16), cell: Cell) {
+        let (foreground, background) = cell.style.colors();
+        let scale = self.scale as usize;
+
+        let mut rect = self.cell_rect(pos);
+
+        if cell.width == CellWidth::Wide {
+            rect.width *= 2;
+        }
+
+        surface.fill_rect(rect, background);
+
+        let glyph = match self.font.glyph(cell.char) {
+            Some(glyph) => Some(glyph),
+            None if box_drawing::draw(surface, rect, cell.char, scale, foreground) => None,
+            None => self.font.replacement(),
+        };
+
+        if let Some(glyph) = glyph {
+            // Glyphs are as wide as a single cell, so they're centered in wide ones.
+            let rect_x = rect.x + (rect.width - self.cell_size().0) / 2;
+
+            for y in 0..glyph.height {
+                for x in 0..glyph.width {
+                    // Bold smears every pixel one to the right.
+                    let fg_pixel = glyph.is_set(x, y) || (cell.style.bold && x != 0 && glyph.is_set(x - 1, y));
 
+                    if !fg_pixel {
+                        continue;
+                    }
+
+                    let draw_x = rect_x as isize + (glyph.x + x as isize) * scale as isize;
+                    let draw_y = rect.y as isize + (glyph.y + y as isize) * scale as isize;
+
+                    // Glyphs which don't fit their cell are cut off, rather than drawing over their neighbours.
+                    if draw_x >= 0 && draw_y >= 0 {
+                        surface.fill_rect(Rect::new(draw_x as usize, draw_y as usize, scale, scale).intersect(rect), foreground);
+                    }
+                }
+            }
+        }
+
+        if cell.style.underline {
+            surface.fill_rect(Rect::new(rect.x, rect.y + rect.height - scale, rect.width, scale), foreground);
+        }
+    }
+}
+
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct TextStyle {
     foreground: Color,
     background: Color,
+
+    bold: bool,
+    underline: bool,
+    reverse: bool,
+}
+
+impl TextStyle {
+    pub fn apply(&mut self, attr: &GraphicsAttributes) {
+        if attr.reset {
+            *self = Self::default();
+        }
+
+        self.bold = attr.bold.unwrap_or(self.bold);
+        self.underline = attr.underline.unwrap_or(self.underline);
+        self.reverse = attr.reverse.unwrap_or(self.reverse);
+
+        self.foreground = attr.foreground_color.unwrap_or(self.foreground);
+        self.background = attr.background_color.unwrap_or(self.background);
+    }
+
+    /// Foreground and background to draw with, swapped when reversed.
+    pub fn colors(&self) -> (Color, Color) {
+        if self.reverse {
+            (self.background, self.foreground)
+        } else {
+            (self.foreground, self.background)
+        }
+    }
+
+    /// Style of erased cells, which only keep the background.
+    pub fn erased(&self) -> Self {
+        Self {
+            background: self.colors().1,
+            ..Self::default()
+        }
+    }
 }
 
 impl Default for TextStyle {
@@ -382,18 +839,97 @@ impl Default for TextStyle {
         TextStyle {
             foreground: ColorName::DefaultForeground.color(),
             background: ColorName::DefaultBackground.color(),
+
+            bold: false,
+            underline: false,
+            reverse: false,
         }
     }
 }
 
-#[derive(Debug)]
-pub(in self) enum CacheType {
-    Char(char),
-    Ansi(Vec<char>),
-}
 
-impl CacheType {
-    pub fn is_char(&self) -> bool {
-        matches!(self, Self::Char(_))
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::display::layout::Layout;
+
+    fn console(lines: &[&str]) -> ConsoleContainer {
+        let mut console = ConsoleContainer::new(Layout::default().split(12, 6));
+
+        for (i, line) in lines.iter().enumerate() {
+            if i != 0 {
+                console.new_line();
+            }
+
+            line.chars().for_each(|char| console.write_output_char(char));
+        }
+
+        console
+    }
+
+    #[test_case]
+    fn backspace_only_moves_the_output_cursor() {
+        let mut console = console(&["abc"]);
+
+        console.handle_backspace();
+        console.handle_backspace();
+        console.write_output_char('X');
+
+        assert_eq!((0..3).map(|x| console.output.get(x, 0).char).collect::<String>(), "aXc");
+    }
+
+    #[test_case]
+    fn output_keeps_the_view_in_place() {
+        let mut console = console(&["a", "b", "c", "d", "e", "f", "g"]);
+        console.scroll_view(1);
+        let shown = console.displayed_cell(0, 0);
+
+        console.new_line();
+        assert_eq!(console.displayed_cell(0, 0), shown);
+    }
+
+    #[test_case]
+    fn scrollback_fits_the_budget() {
+        assert_eq!(scrollback_rows(80), SCROLLBACK_SIZE);
+
+        let rows = scrollback_rows(u16::MAX);
+        assert!(rows < SCROLLBACK_SIZE);
+        assert!(rows * u16::MAX as usize * mem::size_of::<Cell>() * CONSOLE_COUNT <= SCROLLBACK_BUDGET);
+    }
+
+    #[test_case]
+    fn selection_across_lines() {
+        let mut console = console(&["hello world", "foo"]);
+
+        console.start_selection(6, 0);
+        assert_eq!(console.selected_text(), None);
+
+        console.extend_selection(1, 1);
+        assert_eq!(console.selected_text().as_deref(), Some("world\nfo"));
+
+        // Dragging back before the anchor selects from there to the anchor.
+        console.extend_selection(0, 0);
+        assert_eq!(console.selected_text().as_deref(), Some("hello w"));
+    }
+
+    #[test_case]
+    fn selection_skips_blanks_and_spacers() {
+        let mut console = console(&["a\u{6f22}", "b"]);
+
+        console.start_selection(0, 0);
+        console.extend_selection(11, 1);
+        assert_eq!(console.selected_text().as_deref(), Some("a\u{6f22}\nb"));
+    }
+
+    #[test_case]
+    fn selection_follows_scrolled_output() {
+        let mut console = console(&["one", "two"]);
+
+        console.start_selection(0, 1);
+        console.extend_selection(2, 1);
+
+        // Scrolling the output up moves the selected row into the scrollback, along with the selection.
+        console.scroll_up(2);
+        assert_eq!(console.selected_text().as_deref(), Some("two"));
     }
 }
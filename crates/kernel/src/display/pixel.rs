@@ -0,0 +1,112 @@
+use bootloader_api::info::PixelFormat;
+use gbl::io::Color;
+
+/// Pixels larger than this are cut off.
+pub const MAX_BYTES_PER_PIXEL: usize = 8;
+
+
+/// How colors are laid out in the framebuffer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PixelLayout {
+    format: PixelFormat,
+    bytes_per_pixel: usize,
+}
+
+impl PixelLayout {
+    pub fn new(format: PixelFormat, bytes_per_pixel: usize) -> Self {
+        Self {
+            format,
+            bytes_per_pixel: bytes_per_pixel.clamp(1, MAX_BYTES_PER_PIXEL),
+        }
+    }
+
+    pub fn bytes_per_pixel(&self) -> usize {
+        self.bytes_per_pixel
+    }
+
+    /// The bytes of a pixel. Only the first [`PixelLayout::bytes_per_pixel`] are used.
+    pub fn encode(&self, color: Color) -> [u8; MAX_BYTES_PER_PIXEL] {
+        let mut pixel = [0; MAX_BYTES_PER_PIXEL];
+
+        match self.format {
+            PixelFormat::Rgb => pixel[..3].copy_from_slice(&[color.r, color.g, color.b]),
+            PixelFormat::U8 => pixel[0] = luma(color),
+
+            PixelFormat::Unknown { red_position, green_position, blue_position } => {
+                let value = channel(color.r, red_position)
+                    | channel(color.g, green_position)
+                    | channel(color.b, blue_position);
+
+                pixel = value.to_le_bytes();
+            }
+
+            // Bgr is what most firmware uses, so it's the best guess for anything new.
+            _ => pixel[..3].copy_from_slice(&[color.b, color.g, color.r]),
+        }
+
+        pixel
+    }
+
+    /// Reads back a pixel written by [`PixelLayout::encode`].
+    pub fn decode(&self, pixel: &[u8]) -> Color {
+        let mut bytes = [0; MAX_BYTES_PER_PIXEL];
+        let len = pixel.len().min(MAX_BYTES_PER_PIXEL);
+        bytes[..len].copy_from_slice(&pixel[..len]);
+
+        match self.format {
+            PixelFormat::Rgb => Color::new(bytes[0], bytes[1], bytes[2]),
+            PixelFormat::U8 => Color::new(bytes[0], bytes[0], bytes[0]),
+
+            PixelFormat::Unknown { red_position, green_position, blue_position } => {
+                let value = u64::from_le_bytes(bytes);
+
+                Color::new(
+                    value.checked_shr(red_position as u32).unwrap_or(0) as u8,
+                    value.checked_shr(green_position as u32).unwrap_or(0) as u8,
+                    value.checked_shr(blue_position as u32).unwrap_or(0) as u8,
+                )
+            }
+
+            _ => Color::new(bytes[2], bytes[1], bytes[0]),
+        }
+    }
+}
+
+
+/// Rec. 601 luma, as 8 bit fixed point.
+fn luma(color: Color) -> u8 {
+    ((color.r as u32 * 77 + color.g as u32 * 150 + color.b as u32 * 29) >> 8) as u8
+}
+
+fn channel(value: u8, position: u8) -> u64 {
+    (value as u64).checked_shl(position as u32).unwrap_or(0)
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const COLOR: Color = Color::new(0x11, 0x22, 0x33);
+
+    #[test_case]
+    fn byte_orders() {
+        assert_eq!(PixelLayout::new(PixelFormat::Rgb, 4).encode(COLOR)[..3], [0x11, 0x22, 0x33]);
+        assert_eq!(PixelLayout::new(PixelFormat::Bgr, 4).encode(COLOR)[..3], [0x33, 0x22, 0x11]);
+    }
+
+    #[test_case]
+    fn bit_positions() {
+        let layout = PixelLayout::new(PixelFormat::Unknown { red_position: 0, green_position: 8, blue_position: 16 }, 4);
+
+        assert_eq!(layout.encode(COLOR)[..4], [0x11, 0x22, 0x33, 0]);
+        assert_eq!(layout.decode(&layout.encode(COLOR)[..4]), COLOR);
+    }
+
+    #[test_case]
+    fn grayscale() {
+        let white = Color::new(0xFF, 0xFF, 0xFF);
+
+        assert_eq!(PixelLayout::new(PixelFormat::U8, 1).encode(white)[0], 0xFF);
+    }
+}
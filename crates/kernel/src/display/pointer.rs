@@ -0,0 +1,137 @@
+use gbl::io::Color;
+
+use super::gfx::Surface;
+
+const WIDTH: usize = 11;
+const HEIGHT: usize = 17;
+
+/// An arrow with its tip at the top left. `#` is the outline, `.` the fill and spaces are see-through.
+const SPRITE: [&[u8; WIDTH]; HEIGHT] = [
+    b"#          ",
+    b"##         ",
+    b"#.#        ",
+    b"#..#       ",
+    b"#...#      ",
+    b"#....#     ",
+    b"#.....#    ",
+    b"#......#   ",
+    b"#.......#  ",
+    b"#........# ",
+    b"#.....#####",
+    b"#..#..#    ",
+    b"#.# #..#   ",
+    b"##  #..#   ",
+    b"#    #..#  ",
+    b"     #..#  ",
+    b"      ##   ",
+];
+
+const OUTLINE: Color = Color::new(0x00, 0x00, 0x00);
+const FILL: Color = Color::new(0xFF, 0xFF, 0xFF);
+
+
+/// The mouse pointer, drawn over everything else.
+///
+/// Whatever it covers is saved when it's drawn, so it can be put back before anything underneath is drawn to.
+pub struct Pointer {
+    /// Pixel the tip points at.
+    position: (usize, usize),
+    visible: bool,
+
+    /// Where the pointer was last drawn, if it's still on the surface.
+    drawn: Option<(usize, usize)>,
+    /// The pixels under the sprite, row by row. `None` past the edges of the surface.
+    background: [Option<Color>; WIDTH * HEIGHT],
+}
+
+impl Pointer {
+    /// A pointer in the top left, hidden until it first moves.
+    pub const fn new() -> Self {
+        Self {
+            position: (0, 0),
+            visible: false,
+
+            drawn: None,
+            background: [None; WIDTH * HEIGHT],
+        }
+    }
+
+    /// Moves and shows the pointer. It's drawn there on the next [`Pointer::show`].
+    pub fn set_position(&mut self, position: (usize, usize)) {
+        self.position = position;
+        self.visible = true;
+    }
+
+    /// Puts back what the pointer covers. Does nothing if it isn't drawn.
+    pub fn hide(&mut self, surface: &mut Surface) {
+        let Some((x, y)) = self.drawn.take() else {
+            return;
+        };
+
+        for (i, color) in self.background.iter().enumerate() {
+            if let Some(color) = *color {
+                surface.set_pixel((x + i % WIDTH) as isize, (y + i / WIDTH) as isize, color);
+            }
+        }
+    }
+
+    /// Draws the pointer, saving what it covers first. Does nothing if it's already drawn.
+    pub fn show(&mut self, surface: &mut Surface) {
+        if !self.visible || self.drawn.is_some() {
+            return;
+        }
+
+        let (x, y) = self.position;
+
+        for (row, line) in SPRITE.iter().enumerate() {
+            for (column, &pixel) in line.iter().enumerate() {
+                let (px, py) = ((x + column) as isize, (y + row) as isize);
+                let saved = &mut self.background[row * WIDTH + column];
+
+                *saved = match pixel {
+                    b'#' | b'.' => surface.get_pixel(px, py),
+                    _ => None,
+                };
+
+                match pixel {
+                    b'#' => surface.set_pixel(px, py, OUTLINE),
+                    b'.' => surface.set_pixel(px, py, FILL),
+                    _ => (),
+                }
+            }
+        }
+
+        self.drawn = Some((x, y));
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec;
+    use bootloader_api::info::PixelFormat;
+
+    use super::*;
+    use crate::display::pixel::PixelLayout;
+
+    const BACKGROUND: Color = Color::new(0x10, 0x20, 0x30);
+
+    #[test_case]
+    fn hiding_restores_the_background() {
+        let mut pixels = vec![0; 16 * 16 * 4];
+        let mut surface = Surface::new(&mut pixels, 16, 16, 16, PixelLayout::new(PixelFormat::Rgb, 4));
+        surface.fill_rect(surface.bounds(), BACKGROUND);
+
+        let mut pointer = Pointer::new();
+        pointer.set_position((10, 10));
+        pointer.show(&mut surface);
+
+        assert_eq!(surface.get_pixel(10, 10), Some(OUTLINE));
+        assert_eq!(surface.get_pixel(11, 12), Some(FILL));
+
+        pointer.hide(&mut surface);
+
+        // Including the parts which were clipped by the edges.
+        assert!((0..16).all(|y| (0..16).all(|x| surface.get_pixel(x, y) == Some(BACKGROUND))));
+    }
+}
@@ -0,0 +1,152 @@
+use core::fmt::{self, Write};
+
+use super::{TextStyle, Typeface, double_buffer::DoubleBuffer, grid::{Cell, Grid}, layout::Region};
+
+
+/// What the status bar shows.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct Status {
+    pub uptime_seconds: u64,
+    /// In KiB. `None` if it couldn't be checked, in which case the last value is kept.
+    pub heap_used: Option<usize>,
+    /// In KiB.
+    pub heap_total: usize,
+    pub tasks: usize,
+    /// Zero based index of the console on screen.
+    pub console: usize,
+}
+
+impl fmt::Display for Status {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let seconds = self.uptime_seconds;
+
+        write!(f, " VT{} | up {:02}:{:02}:{:02} | heap ", self.console + 1, seconds / 3600, seconds / 60 % 60, seconds % 60)?;
+
+        match self.heap_used {
+            Some(used) => write!(f, "{used}")?,
+            None => f.write_char('?')?,
+        }
+
+        write!(f, "/{} KiB | tasks {}", self.heap_total, self.tasks)
+    }
+}
+
+
+/// A bar which is shared by every console.
+pub struct StatusBar {
+    grid: Grid,
+    region: Region,
+
+    /// What's currently written into `grid`.
+    status: Option<Status>,
+}
+
+impl StatusBar {
+    pub fn new(region: Region) -> Self {
+        Self {
+            grid: Grid::new(region.width, region.height),
+            region,
+
+            status: None,
+        }
+    }
+
+    pub fn resize(&mut self, region: Region) {
+        self.grid.resize(region.width, region.height);
+        self.region = region;
+
+        // Rewrite it in full on the next update.
+        self.status = None;
+    }
+
+    /// Rewrites the bar if anything changed.
+    pub fn update(&mut self, status: Status) {
+        let status = Status {
+            heap_used: status.heap_used.or(self.status.and_then(|s| s.heap_used)),
+            ..status
+        };
+
+        if self.status == Some(status) {
+            return;
+        }
+
+        self.status = Some(status);
+
+        let mut writer = CellWriter { grid: &mut self.grid, x: 0 };
+        // Writing into cells can't fail, it's only cut off.
+        let _ = write!(writer, "{status}");
+
+        let x = writer.x;
+        let width = self.grid.width();
+
+        self.grid.fill(0, x..width, Cell::new(' ', style()));
+    }
+
+    pub fn render(&mut self, buffer: &mut DoubleBuffer, typeface: &Typeface) {
+        if !self.grid.is_dirty() {
+            return;
+        }
+
+        buffer.draw(|surface| {
+            for y in 0..self.grid.height() {
+                for x in 0..self.grid.width() {
+                    if self.grid.take_dirty(x, y) {
+                        typeface.draw_cell(surface, (self.region.x + x, self.region.y + y), self.grid.get(x, y));
+                    }
+                }
+            }
+        });
+
+        self.grid.clean();
+    }
+}
+
+
+fn style() -> TextStyle {
+    TextStyle {
+        reverse: true,
+        ..TextStyle::default()
+    }
+}
+
+/// Writes text along the first row of a grid, dropping whatever doesn't fit.
+struct CellWriter<'a> {
+    grid: &'a mut Grid,
+    x: u16,
+}
+
+impl Write for CellWriter<'_> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        for char in s.chars() {
+            if self.x >= self.grid.width() {
+                break;
+            }
+
+            self.grid.set(self.x, 0, Cell::new(char, style()));
+            self.x += 1;
+        }
+
+        Ok(())
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use alloc::format;
+
+    use super::*;
+
+    #[test_case]
+    fn format() {
+        let status = Status {
+            uptime_seconds: 3723,
+            heap_used: Some(512),
+            heap_total: 32768,
+            tasks: 3,
+            console: 0,
+        };
+
+        assert_eq!(format!("{status}"), " VT1 | up 01:02:03 | heap 512/32768 KiB | tasks 3");
+    }
+}
@@ -0,0 +1,71 @@
+use super::{Font, Glyph, glyphs::{FONTS, FONT_HEIGHT, FONT_WIDTH}};
+
+#[derive(Debug)]
+pub struct FontGlyph {
+    pub charlie: char,
+    pub width: u16,
+    pub height: u16,
+    pub off_x: isize,
+    pub off_y: isize,
+    /// A bit per pixel, most significant bit first. Every row starts on a new byte.
+    pub display: &'static [u8]
+}
+
+impl FontGlyph {
+    /// As [`FONTS`] is a const, a glyph which doesn't fit its cell or whose `display` is the wrong size fails the build.
+    pub const fn new(
+        charlie: char,
+        (width, height): (u16, u16),
+        (off_x, off_y): (isize, isize),
+        display: &'static [u8]
+    ) -> Self {
+        assert!(display.len() == height as usize * ((width as usize + 7) / 8), "glyph's display doesn't match its size");
+
+        assert!(off_x >= 0 && off_y >= 0, "glyph is offset out of its cell");
+        assert!(off_x + width as isize <= FONT_WIDTH as isize, "glyph is wider than its cell");
+        assert!(off_y + height as isize <= FONT_HEIGHT as isize, "glyph is taller than its cell");
+
+        Self {
+            charlie,
+            width,
+            height,
+            off_x,
+            off_y,
+            display,
+        }
+    }
+}
+
+// Glyphs are looked up with a binary search.
+const _: () = {
+    let mut i = 1;
+
+    while i < FONTS.len() {
+        assert!((FONTS[i - 1].charlie as u32) < FONTS[i].charlie as u32, "FONTS isn't sorted");
+        i += 1;
+    }
+};
+
+
+/// The font built into the kernel, drawn from [`FONTS`].
+pub struct BuiltinFont;
+
+impl Font for BuiltinFont {
+    fn cell_size(&self) -> (u16, u16) {
+        (FONT_WIDTH, FONT_HEIGHT)
+    }
+
+    fn glyph(&self, value: char) -> Option<Glyph<'_>> {
+        // The table is sorted, but has gaps.
+        let glyph = &FONTS[FONTS.binary_search_by_key(&value, |glyph| glyph.charlie).ok()?];
+
+        // (0, 0) is at the bottom left of the glyph, while its raster starts at the top left.
+        Some(Glyph {
+            x: glyph.off_x,
+            y: (FONT_HEIGHT - glyph.height) as isize - glyph.off_y,
+            width: glyph.width,
+            height: glyph.height,
+            raster: glyph.display,
+        })
+    }
+}
@@ -0,0 +1,2195 @@
+//! Glyphs of the builtin font, generated by `fontgen` from `builtin.bdf`. Edit that instead.
+
+use super::FontGlyph;
+
+pub const FONT_WIDTH: u16 = 7;
+pub const FONT_HEIGHT: u16 = 15;
+
+pub const FONTS: &[FontGlyph] = &[
+    FontGlyph::new('\0', (7, 13), (0, 1), &[
+        0b10101010,
+        0b00000000,
+        0b10000010,
+        0b00000000,
+        0b10000010,
+        0b00000000,
+        0b10000010,
+        0b00000000,
+        0b10000010,
+        0b00000000,
+        0b10000010,
+        0b00000000,
+        0b10101010,
+    ]),
+    FontGlyph::new('\u{1}', (5, 6), (1, 3), &[
+        0b11111000,
+        0b10001000,
+        0b10001000,
+        0b10001000,
+        0b10001000,
+        0b11111000,
+    ]),
+    FontGlyph::new('\u{2}', (5, 6), (1, 3), &[
+        0b11111000,
+        0b10001000,
+        0b10001000,
+        0b10001000,
+        0b10001000,
+        0b11111000,
+    ]),
+    FontGlyph::new('\u{3}', (5, 6), (1, 3), &[
+        0b11111000,
+        0b10001000,
+        0b10001000,
+        0b10001000,
+        0b10001000,
+        0b11111000,
+    ]),
+    FontGlyph::new('\u{4}', (5, 6), (1, 3), &[
+        0b11111000,
+        0b10001000,
+        0b10001000,
+        0b10001000,
+        0b10001000,
+        0b11111000,
+    ]),
+    FontGlyph::new('\u{5}', (5, 6), (1, 3), &[
+        0b11111000,
+        0b10001000,
+        0b10001000,
+        0b10001000,
+        0b10001000,
+        0b11111000,
+    ]),
+    FontGlyph::new('\u{6}', (5, 6), (1, 3), &[
+        0b11111000,
+        0b10001000,
+        0b10001000,
+        0b10001000,
+        0b10001000,
+        0b11111000,
+    ]),
+    FontGlyph::new('\u{7}', (5, 6), (1, 3), &[
+        0b11111000,
+        0b10001000,
+        0b10001000,
+        0b10001000,
+        0b10001000,
+        0b11111000,
+    ]),
+    FontGlyph::new('\u{8}', (5, 6), (1, 3), &[
+        0b11111000,
+        0b10001000,
+        0b10001000,
+        0b10001000,
+        0b10001000,
+        0b11111000,
+    ]),
+    FontGlyph::new('\t', (1, 1), (0, 3), &[
+        0b00000000,
+    ]),
+    FontGlyph::new('\n', (5, 6), (1, 3), &[
+        0b11111000,
+        0b10001000,
+        0b10001000,
+        0b10001000,
+        0b10001000,
+        0b11111000,
+    ]),
+    FontGlyph::new('\u{b}', (5, 6), (1, 3), &[
+        0b11111000,
+        0b10001000,
+        0b10001000,
+        0b10001000,
+        0b10001000,
+        0b11111000,
+    ]),
+    FontGlyph::new('\u{c}', (5, 6), (1, 3), &[
+        0b11111000,
+        0b10001000,
+        0b10001000,
+        0b10001000,
+        0b10001000,
+        0b11111000,
+    ]),
+    FontGlyph::new('\r', (5, 6), (1, 3), &[
+        0b11111000,
+        0b10001000,
+        0b10001000,
+        0b10001000,
+        0b10001000,
+        0b11111000,
+    ]),
+    FontGlyph::new('\u{e}', (4, 7), (0, 3), &[
+        0b00010000,
+        0b00010000,
+        0b00010000,
+        0b00010000,
+        0b00010000,
+        0b00010000,
+        0b11110000,
+    ]),
+    FontGlyph::new('\u{f}', (4, 7), (3, 3), &[
+        0b10000000,
+        0b10000000,
+        0b10000000,
+        0b10000000,
+        0b10000000,
+        0b10000000,
+        0b11110000,
+    ]),
+    FontGlyph::new('\u{10}', (4, 7), (3, 3), &[
+        0b11110000,
+        0b10000000,
+        0b10000000,
+        0b10000000,
+        0b10000000,
+        0b10000000,
+        0b10000000,
+    ]),
+    FontGlyph::new('\u{11}', (4, 7), (0, 3), &[
+        0b11110000,
+        0b00010000,
+        0b00010000,
+        0b00010000,
+        0b00010000,
+        0b00010000,
+        0b00010000,
+    ]),
+    FontGlyph::new('\u{12}', (7, 1), (0, 3), &[
+        0b11111110,
+    ]),
+    FontGlyph::new('\u{13}', (1, 13), (3, 2), &[
+        0b10000000,
+        0b10000000,
+        0b10000000,
+        0b10000000,
+        0b10000000,
+        0b10000000,
+        0b10000000,
+        0b10000000,
+        0b10000000,
+        0b10000000,
+        0b10000000,
+        0b10000000,
+        0b10000000,
+    ]),
+    FontGlyph::new('\u{14}', (4, 13), (0, 2), &[
+        0b00010000,
+        0b00010000,
+        0b00010000,
+        0b00010000,
+        0b00010000,
+        0b00010000,
+        0b11110000,
+        0b00010000,
+        0b00010000,
+        0b00010000,
+        0b00010000,
+        0b00010000,
+        0b00010000,
+    ]),
+    FontGlyph::new('\u{15}', (7, 7), (0, 3), &[
+        0b00010000,
+        0b00010000,
+        0b00010000,
+        0b00010000,
+        0b00010000,
+        0b00010000,
+        0b11111110,
+    ]),
+    FontGlyph::new('\u{16}', (4, 13), (3, 2), &[
+        0b10000000,
+        0b10000000,
+        0b10000000,
+        0b10000000,
+        0b10000000,
+        0b10000000,
+        0b11110000,
+        0b10000000,
+        0b10000000,
+        0b10000000,
+        0b10000000,
+        0b10000000,
+        0b10000000,
+    ]),
+    FontGlyph::new('\u{17}', (7, 7), (0, 3), &[
+        0b11111110,
+        0b00010000,
+        0b00010000,
+        0b00010000,
+        0b00010000,
+        0b00010000,
+        0b00010000,
+    ]),
+    FontGlyph::new('\u{18}', (7, 13), (0, 2), &[
+        0b00010000,
+        0b00010000,
+        0b00010000,
+        0b00010000,
+        0b00010000,
+        0b00010000,
+        0b11111110,
+        0b00010000,
+        0b00010000,
+        0b00010000,
+        0b00010000,
+        0b00010000,
+        0b00010000,
+    ]),
+    FontGlyph::new('\u{19}', (7, 13), (0, 2), &[
+        0b10101010,
+        0b01010100,
+        0b10101010,
+        0b01010100,
+        0b10101010,
+        0b01010100,
+        0b10101010,
+        0b01010100,
+        0b10101010,
+        0b01010100,
+        0b10101010,
+        0b01010100,
+        0b10101010,
+    ]),
+    FontGlyph::new('\u{1a}', (5, 5), (1, 3), &[
+        0b00010000,
+        0b11111000,
+        0b00100000,
+        0b11111000,
+        0b01000000,
+    ]),
+    FontGlyph::new('\u{1b}', (5, 7), (1, 0), &[
+        0b00011000,
+        0b01100000,
+        0b10000000,
+        0b01100000,
+        0b00011000,
+        0b00000000,
+        0b11111000,
+    ]),
+    FontGlyph::new('\u{1c}', (5, 6), (1, 0), &[
+        0b11111000,
+        0b01010000,
+        0b01010000,
+        0b01010000,
+        0b01010000,
+        0b01010000,
+    ]),
+    FontGlyph::new('\u{1d}', (5, 7), (1, 0), &[
+        0b11000000,
+        0b00110000,
+        0b00001000,
+        0b00110000,
+        0b11000000,
+        0b00000000,
+        0b11111000,
+    ]),
+    FontGlyph::new('\u{1e}', (5, 8), (1, 0), &[
+        0b00110000,
+        0b01000000,
+        0b01000000,
+        0b11110000,
+        0b01000000,
+        0b01000000,
+        0b10000000,
+        0b11111000,
+    ]),
+    FontGlyph::new('\u{1f}', (3, 1), (2, 3), &[
+        0b11100000,
+    ]),
+    FontGlyph::new(' ', (1, 1), (5, 3), &[
+        0b00000000,
+    ]),
+    FontGlyph::new('!', (1, 8), (3, 3), &[
+        0b10000000,
+        0b10000000,
+        0b10000000,
+        0b10000000,
+        0b10000000,
+        0b10000000,
+        0b00000000,
+        0b10000000,
+    ]),
+    FontGlyph::new('"', (3, 3), (2, 9), &[
+        0b10100000,
+        0b10100000,
+        0b10100000,
+    ]),
+    FontGlyph::new('#', (5, 8), (1, 3), &[
+        0b01010000,
+        0b01010000,
+        0b11111000,
+        0b01010000,
+        0b01010000,
+        0b11111000,
+        0b01010000,
+        0b01010000,
+    ]),
+    FontGlyph::new('$', (5, 10), (1, 3), &[
+        0b00100000,
+        0b01110000,
+        0b10101000,
+        0b10100000,
+        0b01110000,
+        0b00101000,
+        0b00101000,
+        0b10101000,
+        0b01110000,
+        0b00100000,
+    ]),
+    FontGlyph::new('%', (5, 9), (1, 3), &[
+        0b01000000,
+        0b10100000,
+        0b01001000,
+        0b00010000,
+        0b00100000,
+        0b01000000,
+        0b10010000,
+        0b00101000,
+        0b00010000,
+    ]),
+    FontGlyph::new('&', (5, 9), (1, 3), &[
+        0b00100000,
+        0b01010000,
+        0b01010000,
+        0b00100000,
+        0b01101000,
+        0b10010000,
+        0b10010000,
+        0b10010000,
+        0b01101000,
+    ]),
+    FontGlyph::new('\'', (1, 3), (3, 9), &[
+        0b10000000,
+        0b10000000,
+        0b10000000,
+    ]),
+    FontGlyph::new('(', (3, 11), (2, 3), &[
+        0b00100000,
+        0b01000000,
+        0b01000000,
+        0b10000000,
+        0b10000000,
+        0b10000000,
+        0b10000000,
+        0b10000000,
+        0b01000000,
+        0b01000000,
+        0b00100000,
+    ]),
+    FontGlyph::new(')', (3, 11), (2, 3), &[
+        0b10000000,
+        0b01000000,
+        0b01000000,
+        0b00100000,
+        0b00100000,
+        0b00100000,
+        0b00100000,
+        0b00100000,
+        0b01000000,
+        0b01000000,
+        0b10000000,
+    ]),
+    FontGlyph::new('*', (5, 5), (1, 8), &[
+        0b01010000,
+        0b00100000,
+        0b11111000,
+        0b00100000,
+        0b01010000,
+    ]),
+    FontGlyph::new('+', (5, 5), (1, 4), &[
+        0b00100000,
+        0b00100000,
+        0b11111000,
+        0b00100000,
+        0b00100000,
+    ]),
+    FontGlyph::new(',', (2, 4), (2, 1), &[
+        0b11000000,
+        0b11000000,
+        0b01000000,
+        0b10000000,
+    ]),
+    FontGlyph::new('-', (5, 1), (1, 6), &[
+        0b11111000,
+    ]),
+    FontGlyph::new('.', (2, 2), (2, 3), &[
+        0b11000000,
+        0b11000000,
+    ]),
+    FontGlyph::new('/', (5, 10), (1, 1), &[
+        0b00001000,
+        0b00001000,
+        0b00010000,
+        0b00010000,
+        0b00100000,
+        0b00100000,
+        0b01000000,
+        0b01000000,
+        0b10000000,
+        0b10000000,
+    ]),
+    FontGlyph::new('0', (5, 8), (1, 3), &[
+        0b01110000,
+        0b10001000,
+        0b10001000,
+        0b10101000,
+        0b10101000,
+        0b10001000,
+        0b10001000,
+        0b01110000,
+    ]),
+    FontGlyph::new('1', (5, 8), (1, 3), &[
+        0b00100000,
+        0b01100000,
+        0b10100000,
+        0b00100000,
+        0b00100000,
+        0b00100000,
+        0b00100000,
+        0b11111000,
+    ]),
+    FontGlyph::new('2', (5, 8), (1, 3), &[
+        0b01110000,
+        0b10001000,
+        0b00001000,
+        0b00010000,
+        0b00100000,
+        0b01000000,
+        0b10000000,
+        0b11111000,
+    ]),
+    FontGlyph::new('3', (5, 8), (1, 3), &[
+        0b01110000,
+        0b10001000,
+        0b00001000,
+        0b00110000,
+        0b00001000,
+        0b00001000,
+        0b10001000,
+        0b01110000,
+    ]),
+    FontGlyph::new('4', (6, 8), (1, 3), &[
+        0b00001000,
+        0b00011000,
+        0b00101000,
+        0b01001000,
+        0b10001000,
+        0b11111100,
+        0b00001000,
+        0b00001000,
+    ]),
+    FontGlyph::new('5', (5, 8), (1, 3), &[
+        0b11111000,
+        0b10000000,
+        0b10000000,
+        0b11110000,
+        0b00001000,
+        0b00001000,
+        0b10001000,
+        0b01110000,
+    ]),
+    FontGlyph::new('6', (5, 8), (1, 3), &[
+        0b00110000,
+        0b01000000,
+        0b10000000,
+        0b11110000,
+        0b10001000,
+        0b10001000,
+        0b10001000,
+        0b01110000,
+    ]),
+    FontGlyph::new('7', (5, 8), (1, 3), &[
+        0b11111000,
+        0b00001000,
+        0b00010000,
+        0b00010000,
+        0b00100000,
+        0b00100000,
+        0b01000000,
+        0b01000000,
+    ]),
+    FontGlyph::new('8', (5, 8), (1, 3), &[
+        0b01110000,
+        0b10001000,
+        0b10001000,
+        0b01110000,
+        0b10001000,
+        0b10001000,
+        0b10001000,
+        0b01110000,
+    ]),
+    FontGlyph::new('9', (5, 8), (1, 3), &[
+        0b01110000,
+        0b10001000,
+        0b10001000,
+        0b10001000,
+        0b01111000,
+        0b00001000,
+        0b00010000,
+        0b01100000,
+    ]),
+    FontGlyph::new(':', (2, 6), (2, 3), &[
+        0b11000000,
+        0b11000000,
+        0b00000000,
+        0b00000000,
+        0b11000000,
+        0b11000000,
+    ]),
+    FontGlyph::new(';', (2, 8), (2, 3), &[
+        0b11000000,
+        0b11000000,
+        0b00000000,
+        0b00000000,
+        0b11000000,
+        0b11000000,
+        0b01000000,
+        0b10000000,
+    ]),
+    FontGlyph::new('<', (4, 7), (2, 3), &[
+        0b00010000,
+        0b00100000,
+        0b01000000,
+        0b10000000,
+        0b01000000,
+        0b00100000,
+        0b00010000,
+    ]),
+    FontGlyph::new('=', (5, 3), (1, 5), &[
+        0b11111000,
+        0b00000000,
+        0b11111000,
+    ]),
+    FontGlyph::new('>', (4, 7), (1, 3), &[
+        0b10000000,
+        0b01000000,
+        0b00100000,
+        0b00010000,
+        0b00100000,
+        0b01000000,
+        0b10000000,
+    ]),
+    FontGlyph::new('?', (5, 8), (1, 3), &[
+        0b01110000,
+        0b10001000,
+        0b00001000,
+        0b00010000,
+        0b00100000,
+        0b00100000,
+        0b00000000,
+        0b00100000,
+    ]),
+    FontGlyph::new('@', (5, 8), (1, 3), &[
+        0b01110000,
+        0b10001000,
+        0b10001000,
+        0b10111000,
+        0b10101000,
+        0b10111000,
+        0b10000000,
+        0b01111000,
+    ]),
+    FontGlyph::new('A', (5, 8), (1, 3), &[
+        0b01110000,
+        0b10001000,
+        0b10001000,
+        0b10001000,
+        0b11111000,
+        0b10001000,
+        0b10001000,
+        0b10001000,
+    ]),
+    FontGlyph::new('B', (5, 8), (1, 3), &[
+        0b11110000,
+        0b10001000,
+        0b10001000,
+        0b11110000,
+        0b10001000,
+        0b10001000,
+        0b10001000,
+        0b11110000,
+    ]),
+    FontGlyph::new('C', (5, 8), (1, 3), &[
+        0b01110000,
+        0b10001000,
+        0b10000000,
+        0b10000000,
+        0b10000000,
+        0b10000000,
+        0b10001000,
+        0b01110000,
+    ]),
+    FontGlyph::new('D', (5, 8), (1, 3), &[
+        0b11100000,
+        0b10010000,
+        0b10001000,
+        0b10001000,
+        0b10001000,
+        0b10001000,
+        0b10010000,
+        0b11100000,
+    ]),
+    FontGlyph::new('E', (5, 8), (1, 3), &[
+        0b11111000,
+        0b10000000,
+        0b10000000,
+        0b11110000,
+        0b10000000,
+        0b10000000,
+        0b10000000,
+        0b11111000,
+    ]),
+    FontGlyph::new('F', (5, 8), (1, 3), &[
+        0b11111000,
+        0b10000000,
+        0b10000000,
+        0b11110000,
+        0b10000000,
+        0b10000000,
+        0b10000000,
+        0b10000000,
+    ]),
+    FontGlyph::new('G', (5, 8), (1, 3), &[
+        0b01110000,
+        0b10001000,
+        0b10000000,
+        0b10000000,
+        0b10011000,
+        0b10001000,
+        0b10001000,
+        0b01110000,
+    ]),
+    FontGlyph::new('H', (5, 8), (1, 3), &[
+        0b10001000,
+        0b10001000,
+        0b10001000,
+        0b11111000,
+        0b10001000,
+        0b10001000,
+        0b10001000,
+        0b10001000,
+    ]),
+    FontGlyph::new('I', (3, 8), (2, 3), &[
+        0b11100000,
+        0b01000000,
+        0b01000000,
+        0b01000000,
+        0b01000000,
+        0b01000000,
+        0b01000000,
+        0b11100000,
+    ]),
+    FontGlyph::new('J', (5, 8), (1, 3), &[
+        0b00111000,
+        0b00001000,
+        0b00001000,
+        0b00001000,
+        0b00001000,
+        0b10001000,
+        0b10001000,
+        0b01110000,
+    ]),
+    FontGlyph::new('K', (5, 8), (1, 3), &[
+        0b10001000,
+        0b10010000,
+        0b10100000,
+        0b11100000,
+        0b10010000,
+        0b10010000,
+        0b10001000,
+        0b10001000,
+    ]),
+    FontGlyph::new('L', (5, 8), (1, 3), &[
+        0b10000000,
+        0b10000000,
+        0b10000000,
+        0b10000000,
+        0b10000000,
+        0b10000000,
+        0b10000000,
+        0b11111000,
+    ]),
+    FontGlyph::new('M', (5, 8), (1, 3), &[
+        0b10001000,
+        0b11011000,
+        0b10101000,
+        0b10101000,
+        0b10001000,
+        0b10001000,
+        0b10001000,
+        0b10001000,
+    ]),
+    FontGlyph::new('N', (5, 8), (1, 3), &[
+        0b10001000,
+        0b11001000,
+        0b11001000,
+        0b10101000,
+        0b10101000,
+        0b10011000,
+        0b10011000,
+        0b10001000,
+    ]),
+    FontGlyph::new('O', (5, 8), (1, 3), &[
+        0b01110000,
+        0b10001000,
+        0b10001000,
+        0b10001000,
+        0b10001000,
+        0b10001000,
+        0b10001000,
+        0b01110000,
+    ]),
+    FontGlyph::new('P', (5, 8), (1, 3), &[
+        0b11110000,
+        0b10001000,
+        0b10001000,
+        0b10001000,
+        0b11110000,
+        0b10000000,
+        0b10000000,
+        0b10000000,
+    ]),
+    FontGlyph::new('Q', (5, 9), (1, 3), &[
+        0b01110000,
+        0b10001000,
+        0b10001000,
+        0b10001000,
+        0b10001000,
+        0b10001000,
+        0b10010000,
+        0b01101000,
+        0b00001000,
+    ]),
+    FontGlyph::new('R', (5, 8), (1, 3), &[
+        0b11110000,
+        0b10001000,
+        0b10001000,
+        0b11110000,
+        0b10010000,
+        0b10001000,
+        0b10001000,
+        0b10001000,
+    ]),
+    FontGlyph::new('S', (5, 8), (1, 3), &[
+        0b01110000,
+        0b10001000,
+        0b10000000,
+        0b01110000,
+        0b00001000,
+        0b00001000,
+        0b10001000,
+        0b01110000,
+    ]),
+    FontGlyph::new('T', (5, 8), (1, 3), &[
+        0b11111000,
+        0b00100000,
+        0b00100000,
+        0b00100000,
+        0b00100000,
+        0b00100000,
+        0b00100000,
+        0b00100000,
+    ]),
+    FontGlyph::new('U', (5, 8), (1, 3), &[
+        0b10001000,
+        0b10001000,
+        0b10001000,
+        0b10001000,
+        0b10001000,
+        0b10001000,
+        0b10001000,
+        0b01110000,
+    ]),
+    FontGlyph::new('V', (5, 8), (1, 3), &[
+        0b10001000,
+        0b10001000,
+        0b10001000,
+        0b01010000,
+        0b01010000,
+        0b01010000,
+        0b00100000,
+        0b00100000,
+    ]),
+    FontGlyph::new('W', (5, 8), (1, 3), &[
+        0b10001000,
+        0b10001000,
+        0b10001000,
+        0b10101000,
+        0b10101000,
+        0b01110000,
+        0b01010000,
+        0b01010000,
+    ]),
+    FontGlyph::new('X', (5, 8), (1, 3), &[
+        0b10001000,
+        0b10001000,
+        0b01010000,
+        0b00100000,
+        0b00100000,
+        0b01010000,
+        0b10001000,
+        0b10001000,
+    ]),
+    FontGlyph::new('Y', (5, 8), (1, 3), &[
+        0b10001000,
+        0b10001000,
+        0b10001000,
+        0b01010000,
+        0b00100000,
+        0b00100000,
+        0b00100000,
+        0b00100000,
+    ]),
+    FontGlyph::new('Z', (5, 8), (1, 3), &[
+        0b11111000,
+        0b00010000,
+        0b00100000,
+        0b00100000,
+        0b01000000,
+        0b01000000,
+        0b10000000,
+        0b11111000,
+    ]),
+    FontGlyph::new('[', (3, 11), (2, 2), &[
+        0b11100000,
+        0b10000000,
+        0b10000000,
+        0b10000000,
+        0b10000000,
+        0b10000000,
+        0b10000000,
+        0b10000000,
+        0b10000000,
+        0b10000000,
+        0b11100000,
+    ]),
+    FontGlyph::new('\\', (5, 10), (1, 1), &[
+        0b10000000,
+        0b10000000,
+        0b01000000,
+        0b01000000,
+        0b00100000,
+        0b00100000,
+        0b00010000,
+        0b00010000,
+        0b00001000,
+        0b00001000,
+    ]),
+    FontGlyph::new(']', (3, 11), (2, 2), &[
+        0b11100000,
+        0b00100000,
+        0b00100000,
+        0b00100000,
+        0b00100000,
+        0b00100000,
+        0b00100000,
+        0b00100000,
+        0b00100000,
+        0b00100000,
+        0b11100000,
+    ]),
+    FontGlyph::new('^', (5, 3), (1, 8), &[
+        0b00100000,
+        0b01010000,
+        0b10001000,
+    ]),
+    FontGlyph::new('_', (5, 1), (1, 2), &[
+        0b11111000,
+    ]),
+    FontGlyph::new('`', (2, 2), (2, 10), &[
+        0b10000000,
+        0b01000000,
+    ]),
+    FontGlyph::new('a', (5, 6), (1, 3), &[
+        0b01111000,
+        0b10001000,
+        0b10001000,
+        0b10001000,
+        0b10011000,
+        0b01101000,
+    ]),
+    FontGlyph::new('b', (5, 9), (1, 3), &[
+        0b10000000,
+        0b10000000,
+        0b10000000,
+        0b11110000,
+        0b10001000,
+        0b10001000,
+        0b10001000,
+        0b10001000,
+        0b11110000,
+    ]),
+    FontGlyph::new('c', (5, 6), (1, 3), &[
+        0b01110000,
+        0b10001000,
+        0b10000000,
+        0b10000000,
+        0b10001000,
+        0b01110000,
+    ]),
+    FontGlyph::new('d', (5, 9), (1, 3), &[
+        0b00001000,
+        0b00001000,
+        0b00001000,
+        0b01111000,
+        0b10001000,
+        0b10001000,
+        0b10001000,
+        0b10001000,
+        0b01111000,
+    ]),
+    FontGlyph::new('e', (5, 6), (1, 3), &[
+        0b01110000,
+        0b10001000,
+        0b11111000,
+        0b10000000,
+        0b10001000,
+        0b01110000,
+    ]),
+    FontGlyph::new('f', (5, 9), (1, 3), &[
+        0b00111000,
+        0b01000000,
+        0b01000000,
+        0b11110000,
+        0b01000000,
+        0b01000000,
+        0b01000000,
+        0b01000000,
+        0b01000000,
+    ]),
+    FontGlyph::new('g', (5, 9), (1, 0), &[
+        0b01111000,
+        0b10001000,
+        0b10001000,
+        0b10001000,
+        0b10001000,
+        0b01111000,
+        0b00001000,
+        0b00001000,
+        0b01110000,
+    ]),
+    FontGlyph::new('h', (5, 9), (1, 3), &[
+        0b10000000,
+        0b10000000,
+        0b10000000,
+        0b11110000,
+        0b10001000,
+        0b10001000,
+        0b10001000,
+        0b10001000,
+        0b10001000,
+    ]),
+    FontGlyph::new('i', (4, 8), (2, 3), &[
+        0b01000000,
+        0b00000000,
+        0b11000000,
+        0b01000000,
+        0b01000000,
+        0b01000000,
+        0b01000000,
+        0b00110000,
+    ]),
+    FontGlyph::new('j', (3, 10), (2, 3), &[
+        0b00100000,
+        0b00000000,
+        0b01100000,
+        0b00100000,
+        0b00100000,
+        0b00100000,
+        0b00100000,
+        0b00100000,
+        0b10100000,
+        0b01000000,
+    ]),
+    FontGlyph::new('k', (5, 9), (1, 3), &[
+        0b10000000,
+        0b10000000,
+        0b10000000,
+        0b10001000,
+        0b10010000,
+        0b10100000,
+        0b11100000,
+        0b10010000,
+        0b10001000,
+    ]),
+    FontGlyph::new('l', (3, 9), (2, 3), &[
+        0b11000000,
+        0b01000000,
+        0b01000000,
+        0b01000000,
+        0b01000000,
+        0b01000000,
+        0b01000000,
+        0b01000000,
+        0b01100000,
+    ]),
+    FontGlyph::new('m', (5, 6), (1, 3), &[
+        0b11010000,
+        0b10101000,
+        0b10101000,
+        0b10101000,
+        0b10101000,
+        0b10101000,
+    ]),
+    FontGlyph::new('n', (5, 6), (1, 3), &[
+        0b11110000,
+        0b10001000,
+        0b10001000,
+        0b10001000,
+        0b10001000,
+        0b10001000,
+    ]),
+    FontGlyph::new('o', (5, 6), (1, 3), &[
+        0b01110000,
+        0b10001000,
+        0b10001000,
+        0b10001000,
+        0b10001000,
+        0b01110000,
+    ]),
+    FontGlyph::new('p', (5, 9), (1, 0), &[
+        0b11110000,
+        0b10001000,
+        0b10001000,
+        0b10001000,
+        0b10001000,
+        0b11110000,
+        0b10000000,
+        0b10000000,
+        0b10000000,
+    ]),
+    FontGlyph::new('q', (6, 9), (1, 0), &[
+        0b01111000,
+        0b10001000,
+        0b10001000,
+        0b10001000,
+        0b10001000,
+        0b01111000,
+        0b00001000,
+        0b00001000,
+        0b00001100,
+    ]),
+    FontGlyph::new('r', (5, 6), (1, 3), &[
+        0b11110000,
+        0b10001000,
+        0b10000000,
+        0b10000000,
+        0b10000000,
+        0b10000000,
+    ]),
+    FontGlyph::new('s', (5, 6), (1, 3), &[
+        0b01111000,
+        0b10000000,
+        0b01110000,
+        0b00001000,
+        0b00001000,
+        0b11110000,
+    ]),
+    FontGlyph::new('t', (5, 8), (1, 3), &[
+        0b01000000,
+        0b01000000,
+        0b11110000,
+        0b01000000,
+        0b01000000,
+        0b01000000,
+        0b01000000,
+        0b00111000,
+    ]),
+    FontGlyph::new('u', (5, 6), (1, 3), &[
+        0b10001000,
+        0b10001000,
+        0b10001000,
+        0b10001000,
+        0b10001000,
+        0b01111000,
+    ]),
+    FontGlyph::new('v', (5, 6), (1, 3), &[
+        0b10001000,
+        0b10001000,
+        0b01010000,
+        0b01010000,
+        0b00100000,
+        0b00100000,
+    ]),
+    FontGlyph::new('w', (5, 6), (1, 3), &[
+        0b10001000,
+        0b10001000,
+        0b10101000,
+        0b10101000,
+        0b01010000,
+        0b01010000,
+    ]),
+    FontGlyph::new('x', (5, 6), (1, 3), &[
+        0b10001000,
+        0b01010000,
+        0b00100000,
+        0b00100000,
+        0b01010000,
+        0b10001000,
+    ]),
+    FontGlyph::new('y', (5, 9), (1, 0), &[
+        0b10001000,
+        0b10001000,
+        0b10001000,
+        0b10001000,
+        0b10001000,
+        0b01111000,
+        0b00001000,
+        0b00001000,
+        0b01110000,
+    ]),
+    FontGlyph::new('z', (5, 6), (1, 3), &[
+        0b11111000,
+        0b00010000,
+        0b00100000,
+        0b01000000,
+        0b10000000,
+        0b11111000,
+    ]),
+    FontGlyph::new('{', (5, 11), (1, 3), &[
+        0b00011000,
+        0b00100000,
+        0b00100000,
+        0b00100000,
+        0b00100000,
+        0b11000000,
+        0b00100000,
+        0b00100000,
+        0b00100000,
+        0b00100000,
+        0b00011000,
+    ]),
+    FontGlyph::new('|', (1, 13), (2, 1), &[
+        0b10000000,
+        0b10000000,
+        0b10000000,
+        0b10000000,
+        0b10000000,
+        0b10000000,
+        0b10000000,
+        0b10000000,
+        0b10000000,
+        0b10000000,
+        0b10000000,
+        0b10000000,
+        0b10000000,
+    ]),
+    FontGlyph::new('}', (5, 11), (1, 3), &[
+        0b11000000,
+        0b00100000,
+        0b00100000,
+        0b00100000,
+        0b00100000,
+        0b00011000,
+        0b00100000,
+        0b00100000,
+        0b00100000,
+        0b00100000,
+        0b11000000,
+    ]),
+    FontGlyph::new('~', (5, 3), (1, 9), &[
+        0b01001000,
+        0b10101000,
+        0b10010000,
+    ]),
+    FontGlyph::new('\u{a0}', (1, 1), (5, 3), &[
+        0b00000000,
+    ]),
+    FontGlyph::new('¡', (1, 8), (3, 3), &[
+        0b10000000,
+        0b00000000,
+        0b10000000,
+        0b10000000,
+        0b10000000,
+        0b10000000,
+        0b10000000,
+        0b10000000,
+    ]),
+    FontGlyph::new('¢', (5, 8), (1, 2), &[
+        0b00100000,
+        0b01111000,
+        0b10100000,
+        0b10100000,
+        0b10100000,
+        0b10100000,
+        0b01111000,
+        0b00100000,
+    ]),
+    FontGlyph::new('£', (5, 8), (1, 3), &[
+        0b00110000,
+        0b01001000,
+        0b01000000,
+        0b11110000,
+        0b01000000,
+        0b01000000,
+        0b01000000,
+        0b11111000,
+    ]),
+    FontGlyph::new('¤', (5, 5), (1, 5), &[
+        0b10001000,
+        0b01110000,
+        0b01010000,
+        0b01110000,
+        0b10001000,
+    ]),
+    FontGlyph::new('¥', (5, 8), (1, 3), &[
+        0b10001000,
+        0b10001000,
+        0b01010000,
+        0b11111000,
+        0b00100000,
+        0b11111000,
+        0b00100000,
+        0b00100000,
+    ]),
+    FontGlyph::new('¦', (1, 8), (3, 3), &[
+        0b10000000,
+        0b10000000,
+        0b10000000,
+        0b10000000,
+        0b00000000,
+        0b10000000,
+        0b10000000,
+        0b10000000,
+    ]),
+    FontGlyph::new('§', (5, 8), (1, 4), &[
+        0b01111000,
+        0b10000000,
+        0b01110000,
+        0b10001000,
+        0b10001000,
+        0b01110000,
+        0b00001000,
+        0b11110000,
+    ]),
+    FontGlyph::new('¨', (3, 1), (2, 10), &[
+        0b10100000,
+    ]),
+    FontGlyph::new('©', (7, 8), (0, 3), &[
+        0b01111100,
+        0b10000010,
+        0b10011010,
+        0b10100010,
+        0b10100010,
+        0b10011010,
+        0b10000010,
+        0b01111100,
+    ]),
+    FontGlyph::new('ª', (4, 7), (1, 5), &[
+        0b01100000,
+        0b00010000,
+        0b01110000,
+        0b10010000,
+        0b01110000,
+        0b00000000,
+        0b11110000,
+    ]),
+    FontGlyph::new('«', (6, 5), (0, 4), &[
+        0b00100100,
+        0b01001000,
+        0b10010000,
+        0b01001000,
+        0b00100100,
+    ]),
+    FontGlyph::new('¬', (5, 3), (1, 5), &[
+        0b11111000,
+        0b00001000,
+        0b00001000,
+    ]),
+    FontGlyph::new('\u{ad}', (5, 1), (1, 6), &[
+        0b11111000,
+    ]),
+    FontGlyph::new('®', (7, 8), (0, 3), &[
+        0b01111100,
+        0b10000010,
+        0b10110010,
+        0b10101010,
+        0b10110010,
+        0b10101010,
+        0b10000010,
+        0b01111100,
+    ]),
+    FontGlyph::new('¯', (5, 1), (1, 11), &[
+        0b11111000,
+    ]),
+    FontGlyph::new('°', (3, 3), (2, 9), &[
+        0b01000000,
+        0b10100000,
+        0b01000000,
+    ]),
+    FontGlyph::new('±', (5, 7), (1, 3), &[
+        0b00100000,
+        0b00100000,
+        0b11111000,
+        0b00100000,
+        0b00100000,
+        0b00000000,
+        0b11111000,
+    ]),
+    FontGlyph::new('²', (3, 5), (2, 8), &[
+        0b11000000,
+        0b00100000,
+        0b01000000,
+        0b10000000,
+        0b11100000,
+    ]),
+    FontGlyph::new('³', (3, 5), (2, 8), &[
+        0b11000000,
+        0b00100000,
+        0b01000000,
+        0b00100000,
+        0b11000000,
+    ]),
+    FontGlyph::new('´', (2, 2), (3, 10), &[
+        0b01000000,
+        0b10000000,
+    ]),
+    FontGlyph::new('µ', (5, 8), (1, 1), &[
+        0b10001000,
+        0b10001000,
+        0b10001000,
+        0b10001000,
+        0b10001000,
+        0b11111000,
+        0b10000000,
+        0b10000000,
+    ]),
+    FontGlyph::new('¶', (5, 8), (1, 3), &[
+        0b01111000,
+        0b11101000,
+        0b11101000,
+        0b01101000,
+        0b00101000,
+        0b00101000,
+        0b00101000,
+        0b00101000,
+    ]),
+    FontGlyph::new('·', (1, 1), (3, 6), &[
+        0b10000000,
+    ]),
+    FontGlyph::new('¸', (2, 2), (2, 1), &[
+        0b01000000,
+        0b11000000,
+    ]),
+    FontGlyph::new('¹', (3, 5), (2, 8), &[
+        0b01000000,
+        0b11000000,
+        0b01000000,
+        0b01000000,
+        0b11100000,
+    ]),
+    FontGlyph::new('º', (5, 6), (1, 6), &[
+        0b01110000,
+        0b10001000,
+        0b10001000,
+        0b01110000,
+        0b00000000,
+        0b11111000,
+    ]),
+    FontGlyph::new('»', (6, 5), (1, 4), &[
+        0b10010000,
+        0b01001000,
+        0b00100100,
+        0b01001000,
+        0b10010000,
+    ]),
+    FontGlyph::new('¼', (7, 10), (0, 2), &[
+        0b01000000,
+        0b11000010,
+        0b01000100,
+        0b01001000,
+        0b00010000,
+        0b00100100,
+        0b01001100,
+        0b10010100,
+        0b00011110,
+        0b00000100,
+    ]),
+    FontGlyph::new('½', (7, 10), (0, 2), &[
+        0b01000000,
+        0b11000010,
+        0b01000100,
+        0b01001000,
+        0b00010000,
+        0b00101100,
+        0b01000010,
+        0b10000100,
+        0b00001000,
+        0b00011110,
+    ]),
+    FontGlyph::new('¾', (7, 10), (0, 2), &[
+        0b11000000,
+        0b00100010,
+        0b01000100,
+        0b00101000,
+        0b11010000,
+        0b00100100,
+        0b01001100,
+        0b10010100,
+        0b00011110,
+        0b00000100,
+    ]),
+    FontGlyph::new('¿', (5, 8), (1, 3), &[
+        0b00100000,
+        0b00000000,
+        0b00100000,
+        0b00100000,
+        0b01000000,
+        0b10000000,
+        0b10001000,
+        0b01110000,
+    ]),
+    FontGlyph::new('À', (5, 11), (1, 3), &[
+        0b01000000,
+        0b00100000,
+        0b00000000,
+        0b01110000,
+        0b10001000,
+        0b10001000,
+        0b10001000,
+        0b11111000,
+        0b10001000,
+        0b10001000,
+        0b10001000,
+    ]),
+    FontGlyph::new('Á', (5, 11), (1, 3), &[
+        0b00010000,
+        0b00100000,
+        0b00000000,
+        0b01110000,
+        0b10001000,
+        0b10001000,
+        0b10001000,
+        0b11111000,
+        0b10001000,
+        0b10001000,
+        0b10001000,
+    ]),
+    FontGlyph::new('Â', (5, 11), (1, 3), &[
+        0b00100000,
+        0b01010000,
+        0b00000000,
+        0b01110000,
+        0b10001000,
+        0b10001000,
+        0b10001000,
+        0b11111000,
+        0b10001000,
+        0b10001000,
+        0b10001000,
+    ]),
+    FontGlyph::new('Ã', (5, 11), (1, 3), &[
+        0b01101000,
+        0b10110000,
+        0b00000000,
+        0b01110000,
+        0b10001000,
+        0b10001000,
+        0b10001000,
+        0b11111000,
+        0b10001000,
+        0b10001000,
+        0b10001000,
+    ]),
+    FontGlyph::new('Ä', (5, 10), (1, 3), &[
+        0b01010000,
+        0b00000000,
+        0b01110000,
+        0b10001000,
+        0b10001000,
+        0b10001000,
+        0b11111000,
+        0b10001000,
+        0b10001000,
+        0b10001000,
+    ]),
+    FontGlyph::new('Å', (5, 12), (1, 3), &[
+        0b00100000,
+        0b01010000,
+        0b00100000,
+        0b00000000,
+        0b01110000,
+        0b10001000,
+        0b10001000,
+        0b10001000,
+        0b11111000,
+        0b10001000,
+        0b10001000,
+        0b10001000,
+    ]),
+    FontGlyph::new('Æ', (7, 8), (0, 3), &[
+        0b00111110,
+        0b01001000,
+        0b10001000,
+        0b10001110,
+        0b11111000,
+        0b10001000,
+        0b10001000,
+        0b10001110,
+    ]),
+    FontGlyph::new('Ç', (5, 10), (1, 1), &[
+        0b01110000,
+        0b10001000,
+        0b10000000,
+        0b10000000,
+        0b10000000,
+        0b10000000,
+        0b10001000,
+        0b01110000,
+        0b00100000,
+        0b01100000,
+    ]),
+    FontGlyph::new('È', (5, 11), (1, 3), &[
+        0b01000000,
+        0b00100000,
+        0b00000000,
+        0b11111000,
+        0b10000000,
+        0b10000000,
+        0b11110000,
+        0b10000000,
+        0b10000000,
+        0b10000000,
+        0b11111000,
+    ]),
+    FontGlyph::new('É', (5, 11), (1, 3), &[
+        0b00010000,
+        0b00100000,
+        0b00000000,
+        0b11111000,
+        0b10000000,
+        0b10000000,
+        0b11110000,
+        0b10000000,
+        0b10000000,
+        0b10000000,
+        0b11111000,
+    ]),
+    FontGlyph::new('Ê', (5, 11), (1, 3), &[
+        0b00100000,
+        0b01010000,
+        0b00000000,
+        0b11111000,
+        0b10000000,
+        0b10000000,
+        0b11110000,
+        0b10000000,
+        0b10000000,
+        0b10000000,
+        0b11111000,
+    ]),
+    FontGlyph::new('Ë', (5, 10), (1, 3), &[
+        0b01010000,
+        0b00000000,
+        0b11111000,
+        0b10000000,
+        0b10000000,
+        0b11110000,
+        0b10000000,
+        0b10000000,
+        0b10000000,
+        0b11111000,
+    ]),
+    FontGlyph::new('Ì', (3, 11), (2, 3), &[
+        0b10000000,
+        0b01000000,
+        0b00000000,
+        0b11100000,
+        0b01000000,
+        0b01000000,
+        0b01000000,
+        0b01000000,
+        0b01000000,
+        0b01000000,
+        0b11100000,
+    ]),
+    FontGlyph::new('Í', (3, 11), (2, 3), &[
+        0b00100000,
+        0b01000000,
+        0b00000000,
+        0b11100000,
+        0b01000000,
+        0b01000000,
+        0b01000000,
+        0b01000000,
+        0b01000000,
+        0b01000000,
+        0b11100000,
+    ]),
+    FontGlyph::new('Î', (3, 11), (2, 3), &[
+        0b01000000,
+        0b10100000,
+        0b00000000,
+        0b11100000,
+        0b01000000,
+        0b01000000,
+        0b01000000,
+        0b01000000,
+        0b01000000,
+        0b01000000,
+        0b11100000,
+    ]),
+    FontGlyph::new('Ï', (3, 10), (2, 3), &[
+        0b10100000,
+        0b00000000,
+        0b11100000,
+        0b01000000,
+        0b01000000,
+        0b01000000,
+        0b01000000,
+        0b01000000,
+        0b01000000,
+        0b11100000,
+    ]),
+    FontGlyph::new('Ð', (6, 8), (0, 3), &[
+        0b01110000,
+        0b01001000,
+        0b01000100,
+        0b01000100,
+        0b11100100,
+        0b01000100,
+        0b01001000,
+        0b01110000,
+    ]),
+    FontGlyph::new('Ñ', (5, 11), (1, 3), &[
+        0b01101000,
+        0b10110000,
+        0b00000000,
+        0b10001000,
+        0b11001000,
+        0b11001000,
+        0b10101000,
+        0b10101000,
+        0b10011000,
+        0b10011000,
+        0b10001000,
+    ]),
+    FontGlyph::new('Ò', (5, 11), (1, 3), &[
+        0b01000000,
+        0b00100000,
+        0b00000000,
+        0b01110000,
+        0b10001000,
+        0b10001000,
+        0b10001000,
+        0b10001000,
+        0b10001000,
+        0b10001000,
+        0b01110000,
+    ]),
+    FontGlyph::new('Ó', (5, 11), (1, 3), &[
+        0b00010000,
+        0b00100000,
+        0b00000000,
+        0b01110000,
+        0b10001000,
+        0b10001000,
+        0b10001000,
+        0b10001000,
+        0b10001000,
+        0b10001000,
+        0b01110000,
+    ]),
+    FontGlyph::new('Ô', (5, 11), (1, 3), &[
+        0b00100000,
+        0b01010000,
+        0b00000000,
+        0b01110000,
+        0b10001000,
+        0b10001000,
+        0b10001000,
+        0b10001000,
+        0b10001000,
+        0b10001000,
+        0b01110000,
+    ]),
+    FontGlyph::new('Õ', (5, 11), (1, 3), &[
+        0b01101000,
+        0b10110000,
+        0b00000000,
+        0b01110000,
+        0b10001000,
+        0b10001000,
+        0b10001000,
+        0b10001000,
+        0b10001000,
+        0b10001000,
+        0b01110000,
+    ]),
+    FontGlyph::new('Ö', (5, 10), (1, 3), &[
+        0b01010000,
+        0b00000000,
+        0b01110000,
+        0b10001000,
+        0b10001000,
+        0b10001000,
+        0b10001000,
+        0b10001000,
+        0b10001000,
+        0b01110000,
+    ]),
+    FontGlyph::new('×', (5, 5), (1, 4), &[
+        0b10001000,
+        0b01010000,
+        0b00100000,
+        0b01010000,
+        0b10001000,
+    ]),
+    FontGlyph::new('Ø', (7, 8), (0, 3), &[
+        0b00111010,
+        0b01000100,
+        0b01001100,
+        0b01010100,
+        0b01010100,
+        0b01100100,
+        0b01000100,
+        0b10111000,
+    ]),
+    FontGlyph::new('Ù', (5, 11), (1, 3), &[
+        0b01000000,
+        0b00100000,
+        0b00000000,
+        0b10001000,
+        0b10001000,
+        0b10001000,
+        0b10001000,
+        0b10001000,
+        0b10001000,
+        0b10001000,
+        0b01110000,
+    ]),
+    FontGlyph::new('Ú', (5, 11), (1, 3), &[
+        0b00010000,
+        0b00100000,
+        0b00000000,
+        0b10001000,
+        0b10001000,
+        0b10001000,
+        0b10001000,
+        0b10001000,
+        0b10001000,
+        0b10001000,
+        0b01110000,
+    ]),
+    FontGlyph::new('Û', (5, 11), (1, 3), &[
+        0b00100000,
+        0b01010000,
+        0b00000000,
+        0b10001000,
+        0b10001000,
+        0b10001000,
+        0b10001000,
+        0b10001000,
+        0b10001000,
+        0b10001000,
+        0b01110000,
+    ]),
+    FontGlyph::new('Ü', (5, 10), (1, 3), &[
+        0b01010000,
+        0b00000000,
+        0b10001000,
+        0b10001000,
+        0b10001000,
+        0b10001000,
+        0b10001000,
+        0b10001000,
+        0b10001000,
+        0b01110000,
+    ]),
+    FontGlyph::new('Ý', (5, 11), (1, 3), &[
+        0b00010000,
+        0b00100000,
+        0b00000000,
+        0b10001000,
+        0b10001000,
+        0b10001000,
+        0b01010000,
+        0b00100000,
+        0b00100000,
+        0b00100000,
+        0b00100000,
+    ]),
+    FontGlyph::new('Þ', (5, 8), (1, 3), &[
+        0b10000000,
+        0b11110000,
+        0b10001000,
+        0b10001000,
+        0b11110000,
+        0b10000000,
+        0b10000000,
+        0b10000000,
+    ]),
+    FontGlyph::new('ß', (5, 9), (1, 3), &[
+        0b01100000,
+        0b10010000,
+        0b10010000,
+        0b10100000,
+        0b10010000,
+        0b10001000,
+        0b10001000,
+        0b10001000,
+        0b10110000,
+    ]),
+    FontGlyph::new('à', (5, 9), (1, 3), &[
+        0b01000000,
+        0b00100000,
+        0b00000000,
+        0b01111000,
+        0b10001000,
+        0b10001000,
+        0b10001000,
+        0b10011000,
+        0b01101000,
+    ]),
+    FontGlyph::new('á', (5, 9), (1, 3), &[
+        0b00010000,
+        0b00100000,
+        0b00000000,
+        0b01111000,
+        0b10001000,
+        0b10001000,
+        0b10001000,
+        0b10011000,
+        0b01101000,
+    ]),
+    FontGlyph::new('â', (5, 9), (1, 3), &[
+        0b00100000,
+        0b01010000,
+        0b00000000,
+        0b01111000,
+        0b10001000,
+        0b10001000,
+        0b10001000,
+        0b10011000,
+        0b01101000,
+    ]),
+    FontGlyph::new('ã', (5, 9), (1, 3), &[
+        0b01101000,
+        0b10110000,
+        0b00000000,
+        0b01111000,
+        0b10001000,
+        0b10001000,
+        0b10001000,
+        0b10011000,
+        0b01101000,
+    ]),
+    FontGlyph::new('ä', (5, 8), (1, 3), &[
+        0b01010000,
+        0b00000000,
+        0b01111000,
+        0b10001000,
+        0b10001000,
+        0b10001000,
+        0b10011000,
+        0b01101000,
+    ]),
+    FontGlyph::new('å', (5, 10), (1, 3), &[
+        0b00100000,
+        0b01010000,
+        0b00100000,
+        0b00000000,
+        0b01111000,
+        0b10001000,
+        0b10001000,
+        0b10001000,
+        0b10011000,
+        0b01101000,
+    ]),
+    FontGlyph::new('æ', (7, 6), (0, 3), &[
+        0b01101100,
+        0b00010010,
+        0b01111110,
+        0b10010000,
+        0b10010010,
+        0b01101100,
+    ]),
+    FontGlyph::new('ç', (5, 8), (1, 1), &[
+        0b01110000,
+        0b10001000,
+        0b10000000,
+        0b10000000,
+        0b10001000,
+        0b01110000,
+        0b00100000,
+        0b01100000,
+    ]),
+    FontGlyph::new('è', (5, 9), (1, 3), &[
+        0b01000000,
+        0b00100000,
+        0b00000000,
+        0b01110000,
+        0b10001000,
+        0b11111000,
+        0b10000000,
+        0b10001000,
+        0b01110000,
+    ]),
+    FontGlyph::new('é', (5, 9), (1, 3), &[
+        0b00010000,
+        0b00100000,
+        0b00000000,
+        0b01110000,
+        0b10001000,
+        0b11111000,
+        0b10000000,
+        0b10001000,
+        0b01110000,
+    ]),
+    FontGlyph::new('ê', (5, 9), (1, 3), &[
+        0b00100000,
+        0b01010000,
+        0b00000000,
+        0b01110000,
+        0b10001000,
+        0b11111000,
+        0b10000000,
+        0b10001000,
+        0b01110000,
+    ]),
+    FontGlyph::new('ë', (5, 8), (1, 3), &[
+        0b01010000,
+        0b00000000,
+        0b01110000,
+        0b10001000,
+        0b11111000,
+        0b10000000,
+        0b10001000,
+        0b01110000,
+    ]),
+    FontGlyph::new('ì', (4, 9), (2, 3), &[
+        0b10000000,
+        0b01000000,
+        0b00000000,
+        0b11000000,
+        0b01000000,
+        0b01000000,
+        0b01000000,
+        0b01000000,
+        0b00110000,
+    ]),
+    FontGlyph::new('í', (4, 9), (2, 3), &[
+        0b00100000,
+        0b01000000,
+        0b00000000,
+        0b11000000,
+        0b01000000,
+        0b01000000,
+        0b01000000,
+        0b01000000,
+        0b00110000,
+    ]),
+    FontGlyph::new('î', (4, 9), (2, 3), &[
+        0b01000000,
+        0b10100000,
+        0b00000000,
+        0b11000000,
+        0b01000000,
+        0b01000000,
+        0b01000000,
+        0b01000000,
+        0b00110000,
+    ]),
+    FontGlyph::new('ï', (4, 8), (2, 3), &[
+        0b10100000,
+        0b00000000,
+        0b11000000,
+        0b01000000,
+        0b01000000,
+        0b01000000,
+        0b01000000,
+        0b00110000,
+    ]),
+    FontGlyph::new('ð', (5, 9), (1, 3), &[
+        0b01101000,
+        0b00010000,
+        0b00101000,
+        0b01111000,
+        0b10001000,
+        0b10001000,
+        0b10001000,
+        0b10001000,
+        0b01110000,
+    ]),
+    FontGlyph::new('ñ', (5, 9), (1, 3), &[
+        0b01101000,
+        0b10110000,
+        0b00000000,
+        0b11110000,
+        0b10001000,
+        0b10001000,
+        0b10001000,
+        0b10001000,
+        0b10001000,
+    ]),
+    FontGlyph::new('ò', (5, 9), (1, 3), &[
+        0b01000000,
+        0b00100000,
+        0b00000000,
+        0b01110000,
+        0b10001000,
+        0b10001000,
+        0b10001000,
+        0b10001000,
+        0b01110000,
+    ]),
+    FontGlyph::new('ó', (5, 9), (1, 3), &[
+        0b00010000,
+        0b00100000,
+        0b00000000,
+        0b01110000,
+        0b10001000,
+        0b10001000,
+        0b10001000,
+        0b10001000,
+        0b01110000,
+    ]),
+    FontGlyph::new('ô', (5, 9), (1, 3), &[
+        0b00100000,
+        0b01010000,
+        0b00000000,
+        0b01110000,
+        0b10001000,
+        0b10001000,
+        0b10001000,
+        0b10001000,
+        0b01110000,
+    ]),
+    FontGlyph::new('õ', (5, 9), (1, 3), &[
+        0b01101000,
+        0b10110000,
+        0b00000000,
+        0b01110000,
+        0b10001000,
+        0b10001000,
+        0b10001000,
+        0b10001000,
+        0b01110000,
+    ]),
+    FontGlyph::new('ö', (5, 8), (1, 3), &[
+        0b01010000,
+        0b00000000,
+        0b01110000,
+        0b10001000,
+        0b10001000,
+        0b10001000,
+        0b10001000,
+        0b01110000,
+    ]),
+    FontGlyph::new('÷', (5, 5), (1, 5), &[
+        0b00100000,
+        0b00000000,
+        0b11111000,
+        0b00000000,
+        0b00100000,
+    ]),
+    FontGlyph::new('ø', (7, 6), (0, 3), &[
+        0b00111010,
+        0b01001100,
+        0b01010100,
+        0b01010100,
+        0b01100100,
+        0b10111000,
+    ]),
+    FontGlyph::new('ù', (5, 9), (1, 3), &[
+        0b01000000,
+        0b00100000,
+        0b00000000,
+        0b10001000,
+        0b10001000,
+        0b10001000,
+        0b10001000,
+        0b10001000,
+        0b01111000,
+    ]),
+    FontGlyph::new('ú', (5, 9), (1, 3), &[
+        0b00010000,
+        0b00100000,
+        0b00000000,
+        0b10001000,
+        0b10001000,
+        0b10001000,
+        0b10001000,
+        0b10001000,
+        0b01111000,
+    ]),
+    FontGlyph::new('û', (5, 9), (1, 3), &[
+        0b00100000,
+        0b01010000,
+        0b00000000,
+        0b10001000,
+        0b10001000,
+        0b10001000,
+        0b10001000,
+        0b10001000,
+        0b01111000,
+    ]),
+    FontGlyph::new('ü', (5, 8), (1, 3), &[
+        0b01010000,
+        0b00000000,
+        0b10001000,
+        0b10001000,
+        0b10001000,
+        0b10001000,
+        0b10001000,
+        0b01111000,
+    ]),
+    FontGlyph::new('ý', (5, 12), (1, 0), &[
+        0b00010000,
+        0b00100000,
+        0b00000000,
+        0b10001000,
+        0b10001000,
+        0b10001000,
+        0b10001000,
+        0b10001000,
+        0b01111000,
+        0b00001000,
+        0b00001000,
+        0b01110000,
+    ]),
+    FontGlyph::new('þ', (5, 12), (1, 0), &[
+        0b10000000,
+        0b10000000,
+        0b10000000,
+        0b11110000,
+        0b10001000,
+        0b10001000,
+        0b10001000,
+        0b10001000,
+        0b11110000,
+        0b10000000,
+        0b10000000,
+        0b10000000,
+    ]),
+    FontGlyph::new('ÿ', (5, 11), (1, 0), &[
+        0b01010000,
+        0b00000000,
+        0b10001000,
+        0b10001000,
+        0b10001000,
+        0b10001000,
+        0b10001000,
+        0b01111000,
+        0b00001000,
+        0b00001000,
+        0b01110000,
+    ]),
+    FontGlyph::new('�', (7, 13), (0, 0), &[
+        0b00010000,
+        0b00111000,
+        0b01111100,
+        0b11000110,
+        0b10111010,
+        0b11111010,
+        0b11110110,
+        0b11101110,
+        0b11101110,
+        0b11111110,
+        0b01101100,
+        0b00111000,
+        0b00010000,
+    ]),
+];
@@ -0,0 +1,177 @@
+//! Fonts the console can be drawn with.
+//!
+//! Other than the builtin font, PSF fonts can be parsed with [`psf::PsfFont::parse`], for example from
+//! `include_bytes!`, and made available with [`register`]. [`init`] registers the two embedded ones, which `fontgen`
+//! made from `builtin.bdf`: the builtin font as PSF1, and a bold version of it as PSF2.
+
+use alloc::{collections::BTreeMap, sync::Arc, vec::Vec};
+use spin::{Lazy, Mutex};
+
+mod builtin;
+mod glyphs;
+pub mod psf;
+
+pub use self::{builtin::{BuiltinFont, FontGlyph}, glyphs::{FONTS, FONT_HEIGHT, FONT_WIDTH}};
+
+/// Name the builtin font is registered under.
+pub const BUILTIN: &str = "builtin";
+
+/// The builtin font as PSF1, whose cells are a pixel wider.
+pub const BUILTIN_PSF: &str = "builtin-psf";
+pub const BOLD: &str = "bold";
+
+/// Drawn for characters a font doesn't have.
+pub const REPLACEMENT_CHARACTER: char = '\u{FFFD}';
+
+static REGISTRY: Lazy<Mutex<BTreeMap<&'static str, Arc<dyn Font>>>> = Lazy::new(|| {
+    let mut fonts = BTreeMap::new();
+    fonts.insert(BUILTIN, Arc::new(BuiltinFont) as Arc<dyn Font>);
+
+    Mutex::new(fonts)
+});
+
+
+pub trait Font: Send + Sync {
+    /// Width and height of every cell, in pixels.
+    fn cell_size(&self) -> (u16, u16);
+
+    /// `None` if the font has nothing to draw for `value`.
+    fn glyph(&self, value: char) -> Option<Glyph<'_>>;
+
+    /// Drawn in place of characters which have no glyph.
+    fn replacement(&self) -> Option<Glyph<'_>> {
+        self.glyph(REPLACEMENT_CHARACTER).or_else(|| self.glyph('?'))
+    }
+}
+
+/// Registers the embedded fonts.
+pub fn init() -> Result<(), &'static str> {
+    register(BUILTIN_PSF, psf::PsfFont::parse(include_bytes!("builtin.psf"))?);
+    register(BOLD, psf::PsfFont::parse(include_bytes!("bold.psf"))?);
+
+    Ok(())
+}
+
+/// Registers a font, replacing any previous font with the same name.
+pub fn register(name: &'static str, font: impl Font + 'static) {
+    REGISTRY.lock().insert(name, Arc::new(font));
+}
+
+pub fn get(name: &str) -> Option<Arc<dyn Font>> {
+    REGISTRY.lock().get(name).cloned()
+}
+
+/// Returns the name of every registered font, sorted.
+pub fn names() -> Vec<&'static str> {
+    REGISTRY.lock().keys().copied().collect()
+}
+
+
+/// Whether `value` takes up two cells, like CJK ideographs and fullwidth forms do.
+pub fn is_wide(value: char) -> bool {
+    matches!(value as u32,
+        0x1100..=0x115F      // Hangul Jamo
+        | 0x2E80..=0x303E    // CJK Radicals to CJK Symbols and Punctuation
+        | 0x3041..=0x33FF    // Hiragana to CJK Compatibility
+        | 0x3400..=0x4DBF    // CJK Unified Ideographs Extension A
+        | 0x4E00..=0x9FFF    // CJK Unified Ideographs
+        | 0xA000..=0xA4CF    // Yi
+        | 0xAC00..=0xD7A3    // Hangul Syllables
+        | 0xF900..=0xFAFF    // CJK Compatibility Ideographs
+        | 0xFE30..=0xFE4F    // CJK Compatibility Forms
+        | 0xFF00..=0xFF60    // Fullwidth Forms
+        | 0xFFE0..=0xFFE6
+        | 0x1F300..=0x1F64F  // Pictographs and Emoticons
+        | 0x1F900..=0x1F9FF  // Supplemental Symbols and Pictographs
+        | 0x20000..=0x2FFFD  // CJK Unified Ideographs Extension B onwards
+        | 0x30000..=0x3FFFD
+    )
+}
+
+
+/// The pixels of a character, placed inside of its cell.
+#[derive(Debug, Clone, Copy)]
+pub struct Glyph<'a> {
+    /// Offset of the raster from the top left of the cell.
+    pub x: isize,
+    pub y: isize,
+
+    pub width: u16,
+    pub height: u16,
+    /// A bit per pixel, most significant bit first. Every row starts on a new byte.
+    pub raster: &'a [u8],
+}
+
+impl Glyph<'_> {
+    /// Whether the pixel at `(x, y)` of the raster is drawn. Anything outside of it isn't.
+    pub fn is_set(&self, x: u16, y: u16) -> bool {
+        if x >= self.width || y >= self.height {
+            return false;
+        }
+
+        let (x, y) = (x as usize, y as usize);
+        let row_size = (self.width as usize + 7) / 8;
+
+        self.raster.get(y * row_size + x / 8).map_or(false, |byte| byte & (0x80 >> (x % 8)) != 0)
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test_case]
+    fn bit_rows_are_padded() {
+        // Two rows of 10 pixels: the first has only its last pixel set, the second only its first.
+        let glyph = Glyph { x: 0, y: 0, width: 10, height: 2, raster: &[0x00, 0x40, 0x80, 0x00] };
+
+        assert!(glyph.is_set(9, 0));
+        assert!(!glyph.is_set(8, 0));
+        assert!(glyph.is_set(0, 1));
+        assert!(!glyph.is_set(10, 1));
+    }
+
+    #[test_case]
+    fn builtin_lookup() {
+        let font = BuiltinFont;
+
+        assert!(font.glyph('é').is_some());
+        assert!(font.glyph('\u{7F}').is_none());
+        assert!(font.glyph('\u{10FFFF}').is_none());
+        assert!(font.replacement().is_some());
+    }
+
+    #[test_case]
+    fn embedded_fonts() {
+        // Registered when the kernel was initialized.
+        assert_eq!(names(), [BOLD, BUILTIN, BUILTIN_PSF]);
+
+        for name in [BUILTIN_PSF, BOLD] {
+            let font = get(name).unwrap();
+
+            assert_eq!(font.cell_size(), (8, FONT_HEIGHT));
+            assert!(font.glyph('é').is_some());
+            assert!(font.glyph('\u{7F}').is_none());
+        }
+
+        // Every pixel of the builtin font is set in the PSF1 one, which draws glyphs in their cell.
+        let (builtin, psf) = (BuiltinFont.glyph('A').unwrap(), get(BUILTIN_PSF).unwrap());
+        let psf = psf.glyph('A').unwrap();
+
+        for y in 0..builtin.height {
+            for x in 0..builtin.width {
+                let (cell_x, cell_y) = ((builtin.x + x as isize) as u16, (builtin.y + y as isize) as u16);
+                assert_eq!(builtin.is_set(x, y), psf.is_set(cell_x, cell_y));
+            }
+        }
+    }
+
+    #[test_case]
+    fn wide() {
+        assert!(is_wide('漢'));
+        assert!(is_wide('Ａ'));
+        assert!(!is_wide('A'));
+        assert!(!is_wide('─'));
+    }
+}
@@ -0,0 +1,272 @@
+//! PC Screen Fonts, the format used by the Linux console. Both version 1 and 2 are supported.
+
+use alloc::collections::BTreeMap;
+
+use super::{Font, Glyph};
+
+const PSF1_MAGIC: [u8; 2] = [0x36, 0x04];
+const PSF1_HEADER_SIZE: usize = 4;
+/// The font has 512 glyphs rather than 256.
+const PSF1_MODE_512: u8 = 0x01;
+const PSF1_MODE_HAS_TABLE: u8 = 0x02;
+const PSF1_MODE_HAS_SEQUENCES: u8 = 0x04;
+const PSF1_SEPARATOR: u16 = 0xFFFF;
+const PSF1_START_SEQUENCE: u16 = 0xFFFE;
+
+const PSF2_MAGIC: [u8; 4] = [0x72, 0xB5, 0x4A, 0x86];
+const PSF2_HEADER_SIZE: usize = 32;
+const PSF2_HAS_TABLE: u32 = 0x01;
+const PSF2_SEPARATOR: u8 = 0xFF;
+const PSF2_START_SEQUENCE: u8 = 0xFE;
+
+
+/// A font parsed from the bytes of a PSF file, which it borrows its glyphs from.
+pub struct PsfFont<'a> {
+    width: u16,
+    height: u16,
+
+    /// Bytes per glyph.
+    glyph_size: usize,
+    glyphs: &'a [u8],
+
+    /// Glyph index of every character in the unicode table. Without a table characters index the glyphs directly.
+    unicode: Option<BTreeMap<char, usize>>,
+}
+
+impl<'a> PsfFont<'a> {
+    pub fn parse(data: &'a [u8]) -> Result<Self, &'static str> {
+        if data.starts_with(&PSF2_MAGIC) {
+            Self::parse_psf2(data)
+        } else if data.starts_with(&PSF1_MAGIC) {
+            Self::parse_psf1(data)
+        } else {
+            Err("not a PSF font")
+        }
+    }
+
+    fn parse_psf1(data: &'a [u8]) -> Result<Self, &'static str> {
+        let header = data.get(..PSF1_HEADER_SIZE).ok_or("truncated header")?;
+        let (mode, height) = (header[2], header[3] as usize);
+
+        if height == 0 {
+            return Err("invalid glyph size");
+        }
+
+        let count = if mode & PSF1_MODE_512 != 0 { 512 } else { 256 };
+        let (glyphs, table) = split_glyphs(data, PSF1_HEADER_SIZE, count, height)?;
+
+        let unicode = (mode & (PSF1_MODE_HAS_TABLE | PSF1_MODE_HAS_SEQUENCES) != 0).then(|| {
+            let entries = table.chunks_exact(2).map(|pair| u16::from_le_bytes([pair[0], pair[1]]));
+
+            let mut unicode = BTreeMap::new();
+            let mut index = 0;
+            let mut in_sequence = false;
+
+            for entry in entries {
+                match entry {
+                    PSF1_SEPARATOR => {
+                        index += 1;
+                        in_sequence = false;
+                    }
+
+                    // Sequences combine several characters into a glyph, which a cell can't hold.
+                    PSF1_START_SEQUENCE => in_sequence = true,
+
+                    entry if !in_sequence => if let Some(char) = char::from_u32(entry as u32) {
+                        unicode.entry(char).or_insert(index);
+                    }
+
+                    _ => (),
+                }
+            }
+
+            unicode
+        });
+
+        Ok(Self {
+            width: 8,
+            height: height as u16,
+
+            glyph_size: height,
+            glyphs,
+
+            unicode,
+        })
+    }
+
+    fn parse_psf2(data: &'a [u8]) -> Result<Self, &'static str> {
+        let header = data.get(..PSF2_HEADER_SIZE).ok_or("truncated header")?;
+        let field = |i: usize| u32::from_le_bytes([header[i], header[i + 1], header[i + 2], header[i + 3]]) as usize;
+
+        let (header_size, flags, count, glyph_size, height, width) =
+            (field(8), field(12) as u32, field(16), field(20), field(24), field(28));
+
+        if width == 0 || height == 0 || width > u16::MAX as usize || height > u16::MAX as usize {
+            return Err("invalid glyph size");
+        }
+
+        if glyph_size < height * ((width + 7) / 8) {
+            return Err("glyphs are too small for their width and height");
+        }
+
+        let (glyphs, table) = split_glyphs(data, header_size, count, glyph_size)?;
+
+        let unicode = (flags & PSF2_HAS_TABLE != 0).then(|| {
+            let mut unicode = BTreeMap::new();
+
+            for (index, entry) in table.split(|&byte| byte == PSF2_SEPARATOR).enumerate() {
+                // Anything after the first sequence is a sequence too.
+                let singles = entry.split(|&byte| byte == PSF2_START_SEQUENCE).next().unwrap_or_default();
+
+                for char in core::str::from_utf8(singles).unwrap_or_default().chars() {
+                    unicode.entry(char).or_insert(index);
+                }
+            }
+
+            unicode
+        });
+
+        Ok(Self {
+            width: width as u16,
+            height: height as u16,
+
+            glyph_size,
+            glyphs,
+
+            unicode,
+        })
+    }
+
+    pub fn glyph_count(&self) -> usize {
+        self.glyphs.len() / self.glyph_size.max(1)
+    }
+
+    fn index(&self, value: char) -> usize {
+        match &self.unicode {
+            Some(unicode) => unicode.get(&value).copied().unwrap_or(usize::MAX),
+            None => value as usize,
+        }
+    }
+}
+
+impl Font for PsfFont<'_> {
+    fn cell_size(&self) -> (u16, u16) {
+        (self.width, self.height)
+    }
+
+    fn glyph(&self, value: char) -> Option<Glyph<'_>> {
+        let start = self.index(value).checked_mul(self.glyph_size)?;
+        let bytes = self.glyphs.get(start..start + self.glyph_size)?;
+
+        Some(Glyph {
+            x: 0,
+            y: 0,
+            width: self.width,
+            height: self.height,
+            raster: bytes,
+        })
+    }
+}
+
+
+/// Splits what follows the header into the glyphs and the unicode table.
+fn split_glyphs(data: &[u8], start: usize, count: usize, glyph_size: usize) -> Result<(&[u8], &[u8]), &'static str> {
+    let end = count.checked_mul(glyph_size)
+        .and_then(|size| size.checked_add(start))
+        .filter(|&end| end <= data.len())
+        .ok_or("truncated glyphs")?;
+
+    Ok((&data[start..end], &data[end..]))
+}
+
+
+#[cfg(test)]
+mod tests {
+    use alloc::{vec, vec::Vec};
+
+    use super::*;
+
+    /// 256 glyphs of 8x2, where glyph `i` has `i` in its first row.
+    fn psf1(mode: u8, table: &[u16]) -> Vec<u8> {
+        let mut data = vec![PSF1_MAGIC[0], PSF1_MAGIC[1], mode, 2];
+
+        for i in 0..=255 {
+            data.extend_from_slice(&[i, 0]);
+        }
+
+        for entry in table {
+            data.extend_from_slice(&entry.to_le_bytes());
+        }
+
+        data
+    }
+
+    /// A 10x2 PSF2 font with two glyphs, where glyph 1 is 'é', or 'e' followed by a combining acute accent.
+    fn psf2() -> Vec<u8> {
+        let mut data = PSF2_MAGIC.to_vec();
+
+        for field in [0, PSF2_HEADER_SIZE as u32, PSF2_HAS_TABLE, 2, 4, 2, 10] {
+            data.extend_from_slice(&field.to_le_bytes());
+        }
+
+        data.extend_from_slice(&[0, 0, 0, 0]);
+        data.extend_from_slice(&[0x80, 0, 0x40, 0]);
+
+        data.extend_from_slice(b" \xFF");
+        data.extend_from_slice("é".as_bytes());
+        data.push(PSF2_START_SEQUENCE);
+        data.extend_from_slice("e\u{301}".as_bytes());
+        data.push(PSF2_SEPARATOR);
+
+        data
+    }
+
+    #[test_case]
+    fn psf1_without_table() {
+        let data = psf1(0, &[]);
+        let font = PsfFont::parse(&data).unwrap();
+
+        assert_eq!(font.cell_size(), (8, 2));
+        assert_eq!(font.glyph_count(), 256);
+
+        // 'A' is 0b0100_0001.
+        let glyph = font.glyph('A').unwrap();
+        assert!(glyph.is_set(1, 0) && glyph.is_set(7, 0));
+        assert!(!glyph.is_set(0, 0));
+
+        assert!(font.glyph('\u{100}').is_none());
+    }
+
+    #[test_case]
+    fn psf1_table() {
+        let data = psf1(PSF1_MODE_HAS_TABLE, &[PSF1_SEPARATOR, 0x2588, PSF1_SEPARATOR]);
+        let font = PsfFont::parse(&data).unwrap();
+
+        // Glyph 1 only has its last pixel set.
+        assert!(font.glyph('\u{2588}').unwrap().is_set(7, 0));
+        assert!(font.glyph('A').is_none());
+    }
+
+    #[test_case]
+    fn psf2_table() {
+        let data = psf2();
+        let font = PsfFont::parse(&data).unwrap();
+
+        assert_eq!(font.cell_size(), (10, 2));
+
+        let glyph = font.glyph('é').unwrap();
+        assert!(glyph.is_set(0, 0) && glyph.is_set(1, 1));
+
+        assert!(font.glyph(' ').is_some());
+        assert!(font.glyph('e').is_none());
+    }
+
+    #[test_case]
+    fn truncated() {
+        let data = psf2();
+
+        assert!(PsfFont::parse(&data[..PSF2_HEADER_SIZE + 4]).is_err());
+        assert!(PsfFont::parse(&data[..3]).is_err());
+        assert!(PsfFont::parse(b"font").is_err());
+    }
+}
@@ -1,5 +1,5 @@
 use spin::Lazy;
-use x86_64::structures::idt::InterruptDescriptorTable;
+use x86_64::{structures::{idt::InterruptDescriptorTable, DescriptorTablePointer}, VirtAddr};
 
 use crate::{gdt, apic::ApicInterruptIndex};
 
@@ -31,32 +31,20 @@ pub fn init() {
         crate::gdt::init();
     }
 
-    // TODO: Move. Mouse Initiation.
-    {
-        use crate::ps2::*;
-
-        if let Err(e) = set_mouse_id(MouseId::Four) {
-            println!("Failed to set the mouse id to four: {e}");
-            // panic!("Failed to set the mouse id to four");
-        }
+    IDT.load();
+}
 
-        // Read it back to check that it worked.
-        match mouse_id() {
-            Ok(id) =>  {
-                println!("The PS/2 mouse ID is: {id:?}");
+/// Reset the CPU by raising an exception it can't handle: with an empty IDT, the breakpoint faults, and so does the
+/// double fault that follows.
+pub fn triple_fault() -> ! {
+    let empty = DescriptorTablePointer { limit: 0, base: VirtAddr::zero() };
 
-                if !matches!(id, MouseId::Four) {
-                    println!("Failed to set the mouse id to four");
-                }
-            }
-
-            Err(e) => {
-                println!("Failed to read the PS/2 mouse ID: {e}");
-            }
-        }
+    unsafe {
+        x86_64::instructions::tables::lidt(&empty);
     }
+    x86_64::instructions::interrupts::int3();
 
-    IDT.load();
+    unreachable!("the CPU survived a triple fault");
 }
 
 mod handlers {
@@ -76,21 +64,34 @@ mod handlers {
             writer.lock().tick();
         }
 
+        crate::time::tick();
+        crate::testing::tick();
+
         unsafe { LAPIC.lock().end_of_interrupt() }
     }
 
     pub extern "x86-interrupt" fn keyboard(_: InterruptStackFrame) {
-        let mut port = Port::new(0x60);
-        let scancode: u8 = unsafe { port.read() };
-
-        // TODO: DETERMINE if quick key combinations can STILL prevent key up codes from activating.
-        crate::task::keyboard::add_scancode(scancode);
+        // Until then, the bytes are replies to the controller's initialization.
+        if ps2::is_initialized() {
+            let mut port = Port::new(0x60);
+            let scancode: u8 = unsafe { port.read() };
+
+            // TODO: DETERMINE if quick key combinations can STILL prevent key up codes from activating.
+            if !ps2::take_led_reply(scancode) {
+                crate::task::keyboard::add_scancode(scancode);
+            }
+        }
 
         unsafe { LAPIC.lock().end_of_interrupt() }
     }
 
     pub extern "x86-interrupt" fn mouse(_: InterruptStackFrame) {
-        let _packet = ps2::read_mouse_packet(&ps2::MouseId::Four);
+        if ps2::is_initialized() {
+            // Fires for every byte, a packet is only queued once all of it arrived.
+            if let Some(packet) = ps2::read_mouse_byte() {
+                crate::task::mouse::add_packet(packet);
+            }
+        }
 
         unsafe { LAPIC.lock().end_of_interrupt() }
     }
@@ -1,17 +1,21 @@
 #![no_std]
+#![cfg_attr(test, no_main)]
 #![feature(
     abi_x86_interrupt,
     alloc_error_handler,
     const_mut_refs,
+    custom_test_frameworks,
     let_chains,
     slice_as_chunks
 )]
+#![test_runner(crate::test_runner)]
+#![reexport_test_harness_main = "test_main"]
 
 extern crate alloc;
 
 #[macro_use] extern crate gbl;
 
-use bootloader_api::BootInfo;
+use bootloader_api::{BootInfo, BootloaderConfig, config::Mapping};
 use gbl::io::ColorName;
 use spin::{Once, Mutex, MutexGuard};
 use x86_64::VirtAddr;
@@ -24,14 +28,20 @@ pub mod thread;
 pub mod task;
 pub mod allocator;
 pub mod apic;
+pub mod clipboard;
 pub mod color;
 pub mod font;
 pub mod gdt;
 pub mod interrupts;
 pub mod memory;
 pub mod serial;
+pub mod shell;
+pub mod testing;
+pub mod time;
 pub mod tracing;
 
+pub use testing::{exit_qemu, test_panic_handler, test_runner, QemuExitCode, Testable};
+
 pub struct Locked<T> {
     inner: Mutex<T>
 }
@@ -44,14 +54,26 @@ impl<T> Locked<T> {
     fn lock(&self) -> MutexGuard<T> {
         self.inner.lock()
     }
+
+    fn try_lock(&self) -> Option<MutexGuard<T>> {
+        self.inner.try_lock()
+    }
 }
 
 pub static PHYSICAL_MEM_OFFSET: Once<u64> = Once::new();
 
+/// Used by the kernel binary and every test binary.
+pub const BOOTLOADER_CONFIG: BootloaderConfig = {
+    let mut config = BootloaderConfig::new_default();
+    config.mappings.physical_memory = Some(Mapping::FixedAddress(0x0000_F000_0000_0000));
+    config
+};
+
 pub fn init(boot_info: &'static mut BootInfo) {
     PHYSICAL_MEM_OFFSET.call_once(|| *boot_info.physical_memory_offset.as_ref().unwrap());
 
     let green = ColorName::Green.ansi();
+    let red = ColorName::Red.ansi();
     let clear = ColorName::DefaultForeground.ansi();
 
     // Heap
@@ -68,6 +90,13 @@ pub fn init(boot_info: &'static mut BootInfo) {
     // Display Initiation
     display::init(boot_info.framebuffer.as_mut());
 
+    // Fonts
+    print!("INIT: Fonts......... ");
+    match font::init() {
+        Ok(()) => println!("[{green}OK{clear}]"),
+        Err(e) => println!("[{red}FAILED{clear}] {e}"),
+    }
+
     // Interrupts
     print!("INIT: Interrupts.... ");
     interrupts::init();
@@ -78,6 +107,17 @@ pub fn init(boot_info: &'static mut BootInfo) {
     apic::init();
     println!("[{green}OK{clear}]");
 
+    // PS/2 Controller. Its timeouts need the APIC timer running.
+    print!("INIT: PS/2.......... ");
+    match ps2::init() {
+        Ok(probe) => {
+            println!("[{green}OK{clear}]");
+            println!("{probe}");
+        }
+
+        Err(e) => println!("[{red}FAILED{clear}] {e}"),
+    }
+
     // Tracing
     print!("INIT: Tracing....... ");
     tracing::init_tracing();
@@ -91,3 +131,21 @@ pub fn hlt_loop() -> ! {
         x86_64::instructions::hlt();
     }
 }
+
+
+#[cfg(test)]
+bootloader_api::entry_point!(test_kernel_main, config = &BOOTLOADER_CONFIG);
+
+#[cfg(test)]
+fn test_kernel_main(boot_info: &'static mut BootInfo) -> ! {
+    init(boot_info);
+    test_main();
+
+    hlt_loop()
+}
+
+#[cfg(test)]
+#[panic_handler]
+fn panic(info: &core::panic::PanicInfo) -> ! {
+    test_panic_handler(info)
+}
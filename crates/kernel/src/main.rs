@@ -8,12 +8,12 @@ extern crate tracing;
 
 use core::panic::PanicInfo;
 
-use bootloader_api::{entry_point, config::Mapping, BootloaderConfig, BootInfo};
+use bootloader_api::{entry_point, BootInfo};
 
 #[macro_use] extern crate gbl;
 
 use gbl::io::ColorName;
-use kernel::{font::{FONTS, validate_fonts}, task::{Task, Executor}};
+use kernel::{font::FONTS, task::{Task, Executor}};
 
 #[panic_handler]
 fn panic_handler(info: &PanicInfo) -> ! {
@@ -22,13 +22,7 @@ fn panic_handler(info: &PanicInfo) -> ! {
     kernel::hlt_loop()
 }
 
-pub static BOOTLOADER_CONFIG: BootloaderConfig = {
-    let mut config = BootloaderConfig::new_default();
-    config.mappings.physical_memory = Some(Mapping::FixedAddress(0x0000_F000_0000_0000));
-    config
-};
-
-entry_point!(kernel_main, config = &BOOTLOADER_CONFIG);
+entry_point!(kernel_main, config = &kernel::BOOTLOADER_CONFIG);
 
 fn kernel_main(boot_info: &'static mut BootInfo) -> ! {
     kernel::init(boot_info);
@@ -57,14 +51,14 @@ fn kernel_main(boot_info: &'static mut BootInfo) -> ! {
 
     println!("{}", ColorName::DefaultForeground.ansi());
 
-    validate_fonts();
-
     for i in 0..13 {
         println!("ASDF FSAF {}", 10usize.pow(i));
     }
 
     executor.spawn(Task::new(kernel::task::keyboard::handle_key_presses()));
+    executor.spawn(Task::new(kernel::task::mouse::handle_mouse()));
     executor.spawn(Task::new(kernel::task::output::handle_output()));
+    executor.spawn(Task::new(kernel::task::shell::handle_commands()));
 
     executor.run();
 }
\ No newline at end of file
@@ -1,6 +1,7 @@
 // Stolen. https://github.com/theseus-os/Theseus/blob/theseus_main/kernel/ps2/src/lib.rs
 
-#![allow(clippy::all, dead_code)]
+use core::{fmt, sync::atomic::{AtomicBool, AtomicU8, Ordering}, time::Duration};
+
 use num_enum::TryFromPrimitive;
 use spin::Mutex;
 use modular_bitfield::{specifiers::{B1, B4, B8}, bitfield, BitfieldSpecifier};
@@ -11,12 +12,73 @@ use HostToMouseCommand::*;
 use DeviceToHostResponse::*;
 use x86_64::instructions::port::Port;
 
+use crate::time;
+
 /// Port used by PS/2 Controller and devices
 static PS2_DATA_PORT: Mutex<Port<u8>> = Mutex::new(Port::new(0x60));
 
 /// Port used to send commands to and receive status from the PS/2 Controller
 static PS2_COMMAND_AND_STATUS_PORT: Mutex<Port<u8>> = Mutex::new(Port::new(0x64));
 
+/// How long the controller gets to take a byte, or to come up with one
+const TIMEOUT: Duration = Duration::from_millis(50);
+/// How long a device gets to run its self test after being reset
+const RESET_TIMEOUT: Duration = Duration::from_millis(1000);
+/// How often a byte is sent again when the device asks for it to be
+const RETRIES: u8 = 3;
+
+/// Ways talking to the controller or its devices can go wrong
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Ps2Error {
+    /// A byte wasn't taken or sent in time, which is also what a missing device looks like
+    Timeout,
+    /// The device asked for a byte to be resent more than [RETRIES] times
+    Resend,
+    /// The controller failed its own test, replying with this instead of 0x55
+    ControllerTestFailed(u8),
+    /// A port failed its interface test
+    PortTestFailed(PortTestResult),
+    /// A device failed the self test it runs after being reset
+    SelfTestFailed,
+    /// A byte which isn't one of the replies to what was sent
+    BadResponse(u8),
+}
+
+impl fmt::Display for Ps2Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Ps2Error::Timeout => write!(f, "timed out"),
+            Ps2Error::Resend => write!(f, "the device kept asking for the command again"),
+            Ps2Error::ControllerTestFailed(reply) => write!(f, "controller self test failed ({reply:#04x})"),
+            Ps2Error::PortTestFailed(result) => write!(f, "port test failed ({result:?})"),
+            Ps2Error::SelfTestFailed => write!(f, "device self test failed"),
+            Ps2Error::BadResponse(reply) => write!(f, "unexpected response {reply:#04x}"),
+        }
+    }
+}
+
+/// Spin until `ready`, giving up with [Ps2Error::Timeout] once `timeout` has passed.
+///
+/// Note: time is counted in timer interrupts. Without interrupts, it's counted in calls to `ready` instead, which
+/// read the status register and take about a microsecond each.
+fn wait_until(timeout: Duration, ready: impl Fn() -> bool) -> Result<(), Ps2Error> {
+    if !x86_64::instructions::interrupts::are_enabled() {
+        let ready = (0..timeout.as_micros()).any(|_| ready());
+        return if ready { Ok(()) } else { Err(Ps2Error::Timeout) };
+    }
+
+    let deadline = time::ticks() + time::ticks_in(timeout);
+
+    while !ready() {
+        if time::ticks() > deadline {
+            return Err(Ps2Error::Timeout);
+        }
+        core::hint::spin_loop();
+    }
+
+    Ok(())
+}
+
 // https://wiki.osdev.org/%228042%22_PS/2_Controller#PS.2F2_Controller_Commands
 // quite a few of these are commented out because they're either unused, deprecated or non-standard.
 /// Command types which can be sent to the PS/2 Controller
@@ -105,16 +167,27 @@ pub enum HostToControllerCommand {
     // /// 0 = pulse line, 1 = don't pulse line; Bit 0 corresponds to the "reset" line.
     // /// The other output lines don't have a standard/defined purpose.
     // PulseOutputLineLowFor6ms = 0xF0,
+
+    /// [PulseOutputLineLowFor6ms] with only bit 0 cleared, which pulses the "reset" line
+    PulseResetLine = 0xFE,
 }
 
-/// Write a command to the PS/2 command port/register
+/// Write a command to the PS/2 command port/register, once the controller has taken the previous byte
 ///
 /// Note: Devices attached to the controller should be disabled
 /// before sending commands that return data, otherwise the output buffer could get overwritten
-pub fn write_command(value: HostToControllerCommand) {
+pub fn write_command(value: HostToControllerCommand) -> Result<(), Ps2Error> {
+    wait_for_input_buffer()?;
     unsafe {
         PS2_COMMAND_AND_STATUS_PORT.lock().write(value as u8);
     }
+    Ok(())
+}
+
+/// reset the CPU by pulsing the controller's reset line
+pub fn reset_cpu() {
+    // if it worked, nothing gets to look at the result
+    let _ = write_command(PulseResetLine);
 }
 
 // workaround for `dead_code` warnings, https://github.com/Robbepop/modular-bitfield/issues/56
@@ -198,12 +271,16 @@ impl From<WritableData> for u8 {
     }
 }
 
-/// Write data to the PS/2 data port.
-///
-/// Note: when writing to the PS/2 controller, it might be necessary to check for
-/// [ControllerToHostStatus] `!input_buffer_full` first.
-fn write_data(value: WritableData) {
+/// Write data to the PS/2 data port, once the controller has taken the previous byte
+fn write_data(value: WritableData) -> Result<(), Ps2Error> {
+    wait_for_input_buffer()?;
     unsafe { PS2_DATA_PORT.lock().write(value.into()) };
+    Ok(())
+}
+
+/// Wait for [ControllerToHostStatus] `!input_buffer_full`, so the next write doesn't overwrite the previous one
+fn wait_for_input_buffer() -> Result<(), Ps2Error> {
+    wait_until(TIMEOUT, || !status_register().input_buffer_full())
 }
 
 // wiki.osdev.org/%228042%22_PS/2_Controller#PS.2F2_Controller_Configuration_Byte
@@ -236,34 +313,40 @@ pub struct ControllerConfigurationByte {
 }
 
 /// read the config of the PS/2 port
-pub fn read_config() -> ControllerConfigurationByte {
-    write_command(ReadFromInternalRAMByte0);
-    ControllerConfigurationByte::from_bytes([read_data()])
+pub fn read_config() -> Result<ControllerConfigurationByte, Ps2Error> {
+    write_command(ReadFromInternalRAMByte0)?;
+    Ok(ControllerConfigurationByte::from_bytes([polling_read()?]))
 }
 
 /// write the new config to the PS/2 command port (0x64)
-pub fn write_config(value: ControllerConfigurationByte) {
-    write_command(WriteToInternalRAMByte0);
-    write_data(WritableData::Configuration(value));
+pub fn write_config(value: ControllerConfigurationByte) -> Result<(), Ps2Error> {
+    write_command(WriteToInternalRAMByte0)?;
+    write_data(WritableData::Configuration(value))
 }
 
-/// Clean the [PS2_DATA_PORT] output buffer, skipping the [ControllerToHostStatus] `output_buffer_full` check
+/// Empty the [PS2_DATA_PORT] output buffer, throwing away whatever the devices sent
 pub fn flush_output_buffer() {
-    read_data();
+    // the buffer only holds a byte, but more might arrive while flushing
+    const VERY_ARBITRARY_FLUSH_LIMIT: u8 = 16;
+    for _ in 0..VERY_ARBITRARY_FLUSH_LIMIT {
+        if !status_register().output_buffer_full() {
+            break;
+        }
+        read_data();
+    }
 }
 
 /// must only be called after writing the [TestController] command
 /// otherwise would read bogus data
-pub fn read_controller_test_result() -> Result<(), &'static str> {
+pub fn read_controller_test_result() -> Result<(), Ps2Error> {
     const CONTROLLER_TEST_PASSED: u8 = 0x55;
-    if read_data() != CONTROLLER_TEST_PASSED {
-        Err("failed PS/2 controller test")
-    } else {
-        Ok(())
+    match polling_read()? {
+        CONTROLLER_TEST_PASSED => Ok(()),
+        reply => Err(Ps2Error::ControllerTestFailed(reply)),
     }
 }
 
-#[derive(TryFromPrimitive)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, TryFromPrimitive)]
 #[repr(u8)]
 pub enum PortTestResult {
     Passed = 0x00,
@@ -275,8 +358,9 @@ pub enum PortTestResult {
 
 /// must only be called after writing the [TestPort1] or [TestPort2] command
 /// otherwise would read bogus data
-pub fn read_port_test_result() -> Result<PortTestResult, &'static str> {
-    read_data().try_into().map_err(|_| "failed to read port test result")
+pub fn read_port_test_result() -> Result<PortTestResult, Ps2Error> {
+    let reply = polling_read()?;
+    reply.try_into().map_err(|_| Ps2Error::BadResponse(reply))
 }
 
 // https://wiki.osdev.org/PS/2_Keyboard#Special_Bytes all other bytes sent by the keyboard are scan codes
@@ -298,36 +382,38 @@ pub enum DeviceToHostResponse {
 }
 
 /// https://wiki.osdev.org/%228042%22_PS/2_Controller#Sending_Bytes_To_Device.2Fs
-fn polling_send(value: HostToDevice) -> Result<(), &'static str> {
-    const VERY_ARBITRARY_TIMEOUT_VALUE: u8 = u8::MAX;
-    for _ in 0..VERY_ARBITRARY_TIMEOUT_VALUE {
-        // so that we don't overwrite the previously sent value
-        if !status_register().input_buffer_full() {
-            write_data(WritableData::HostToDevice(value));
-            return Ok(());
-        }
-    }
-    Err("polling_send timeout value exceeded")
+fn polling_send(value: HostToDevice) -> Result<(), Ps2Error> {
+    write_data(WritableData::HostToDevice(value))
 }
 
 /// https://wiki.osdev.org/%228042%22_PS/2_Controller#Polling, which still has some _maybe relevant information_:
 /// We might still need to disable one of the devices every time we send so we can read_data reliably.
 /// As it currently works, it might only be a problem for older devices, so I won't overoptimize.
-fn polling_receive() -> Result<DeviceToHostResponse, &'static str> {
-    const VERY_ARBITRARY_TIMEOUT_VALUE: u8 = u8::MAX;
-    for _ in 0..VERY_ARBITRARY_TIMEOUT_VALUE {
-        // so that we only read when data is available
-        if status_register().output_buffer_full() {
-            return read_data().try_into().map_err(|_| "failed to read device response");
-        }
-    }
-    Err("polling_receive timeout value exceeded")
+fn polling_read() -> Result<u8, Ps2Error> {
+    polling_read_within(TIMEOUT)
+}
+
+/// [polling_read], waiting for up to `timeout`
+fn polling_read_within(timeout: Duration) -> Result<u8, Ps2Error> {
+    // so that we only read when data is available
+    wait_until(timeout, || status_register().output_buffer_full())?;
+    Ok(read_data())
 }
 
 /// e.g. https://wiki.osdev.org/PS/2_Mouse#Set_Sample_Rate_Example
-fn polling_send_receive(value: HostToDevice) -> Result<DeviceToHostResponse, &'static str> {
+fn polling_send_receive(value: HostToDevice) -> Result<u8, Ps2Error> {
     polling_send(value)?;
-    polling_receive()
+    polling_read()
+}
+
+/// wait for the result of the self test a device runs after being reset
+fn read_self_test_result() -> Result<(), Ps2Error> {
+    let reply = polling_read_within(RESET_TIMEOUT)?;
+    match reply.try_into() {
+        Ok(SelfTestPassed) => Ok(()),
+        Ok(SelfTestFailed1 | SelfTestFailed2) => Err(Ps2Error::SelfTestFailed),
+        _ => Err(Ps2Error::BadResponse(reply)),
+    }
 }
 
 #[derive(Clone)]
@@ -420,31 +506,32 @@ pub enum HostToMouseCommand {
     Reset = 0xFF,       //same
 }
 
-/// write command to the keyboard and handle the result
-fn command_to_keyboard(value: HostToKeyboardCommandOrData) -> Result<(), &'static str> {
-    const RETRIES: u8 = 3;
+/// write a command or its data to a device, sending it again for as long as the device asks for that
+fn command_to_device(value: HostToDevice) -> Result<(), Ps2Error> {
     for _ in 0..=RETRIES {
-        return match polling_send_receive(HostToDevice::Keyboard(value.clone()))? {
-            Acknowledge => Ok(()),
-            ResendCommand => continue,
-            _ => Err("wrong response type")
-        };
+        if let HostToDevice::Mouse(_) = value {
+            // every byte for the mouse goes through the controller, including resent ones
+            write_command(WriteByteToPort2InputBuffer)?;
+        }
+
+        let reply = polling_send_receive(value.clone())?;
+        match reply.try_into() {
+            Ok(Acknowledge) => return Ok(()),
+            Ok(ResendCommand) => continue,
+            _ => return Err(Ps2Error::BadResponse(reply)),
+        }
     }
-    Err("keyboard doesn't support the command or there has been a hardware failure")
+    Err(Ps2Error::Resend)
+}
+
+/// write command to the keyboard and handle the result
+fn command_to_keyboard(value: HostToKeyboardCommandOrData) -> Result<(), Ps2Error> {
+    command_to_device(HostToDevice::Keyboard(value))
 }
 
 /// write command to the mouse and handle the result
-fn command_to_mouse(value: HostToMouseCommandOrData) -> Result<(), &'static str> {
-    write_command(WriteByteToPort2InputBuffer);
-    const RETRIES: u8 = 3;
-    for _ in 0..=RETRIES {
-        return match polling_send_receive(HostToDevice::Mouse(value.clone()))? {
-            Acknowledge => Ok(()),
-            ResendCommand => continue,
-            _ => Err("wrong response type")
-        };
-    }
-    Err("mouse doesn't support the command or there has been a hardware failure")
+fn command_to_mouse(value: HostToMouseCommandOrData) -> Result<(), Ps2Error> {
+    command_to_device(HostToDevice::Mouse(value))
 }
 
 #[bitfield(bits = 24)]
@@ -541,10 +628,10 @@ pub struct MousePacket4 {
     zero2: B1,
 }
 impl MousePacket4 {
-    //TODO: see osdev wiki, might be wrong
     /// often called `z_movement`, renamed to disambiguate
     pub fn scroll_movement(&self) -> i8 {
-        self.z_movement() as i8
+        // sign extend the 4 bit value, e.g. 0b1111 is -1
+        ((self.z_movement() << 4) as i8) >> 4
     }
 }
 
@@ -620,25 +707,54 @@ impl MousePacket {
     }
 }
 
-/// read the correct [MousePacket] according to [MouseId]
-pub fn read_mouse_packet(id: &MouseId) -> MousePacket {
-    match id {
-        MouseId::Zero => MousePacket::Zero(
-            MousePacketGeneric::from_bytes([
-                read_data(), read_data(), read_data()
-            ])
-        ),
-        MouseId::Three => MousePacket::Three(
-            MousePacket3::from_bytes([
-                read_data(), read_data(), read_data(), read_data()
-            ])
-        ),
-        MouseId::Four => MousePacket::Four(
-            MousePacket4::from_bytes([
-                read_data(), read_data(), read_data(), read_data()
-            ])
-        ),
+/// Puts [MousePacket]s together from the mouse's bytes, which arrive with an interrupt each
+#[derive(Default)]
+pub struct MousePacketReader {
+    bytes: [u8; 4],
+    /// how many bytes of the current packet arrived
+    len: usize,
+}
+
+impl MousePacketReader {
+    pub const fn new() -> Self {
+        Self { bytes: [0; 4], len: 0 }
     }
+
+    /// add the next byte, returning the packet it completes according to [MouseId]
+    ///
+    /// The first byte of a packet always has [MousePacketGeneric] `always_one` set. Bytes which should start a
+    /// packet but don't are dropped, so that a byte which got lost only costs the packet it was part of.
+    pub fn push(&mut self, byte: u8, id: MouseId) -> Option<MousePacket> {
+        if self.len == 0 && byte & 0b1000 == 0 {
+            return None;
+        }
+
+        self.bytes[self.len] = byte;
+        self.len += 1;
+
+        let [a, b, c, d] = self.bytes;
+        let packet = match id {
+            MouseId::Zero if self.len == 3 => MousePacket::Zero(MousePacketGeneric::from_bytes([a, b, c])),
+            MouseId::Three if self.len == 4 => MousePacket::Three(MousePacket3::from_bytes([a, b, c, d])),
+            MouseId::Four if self.len == 4 => MousePacket::Four(MousePacket4::from_bytes([a, b, c, d])),
+            _ => return None,
+        };
+
+        self.len = 0;
+        Some(packet)
+    }
+}
+
+static MOUSE_PACKET_READER: Mutex<MousePacketReader> = Mutex::new(MousePacketReader::new());
+
+/// read the byte the mouse's interrupt fired for, returning the [MousePacket] once it's complete
+pub fn read_mouse_byte() -> Option<MousePacket> {
+    let status = status_register();
+    if !status.output_buffer_full() || !status.mouse_output_buffer_full() {
+        return None;
+    }
+
+    MOUSE_PACKET_READER.lock().push(read_data(), packet_mouse_id())
 }
 
 #[derive(Clone)]
@@ -653,14 +769,13 @@ pub enum SampleRate {
 }
 
 /// set PS/2 mouse's sampling rate
-fn set_mouse_sampling_rate(value: SampleRate) -> Result<(), &'static str> {
+fn set_mouse_sampling_rate(value: SampleRate) -> Result<(), Ps2Error> {
     command_to_mouse(HostToMouseCommandOrData::MouseCommand(SampleRate))
         .and_then(|_| command_to_mouse(HostToMouseCommandOrData::SampleRate(value)))
-        .map_err(|_| "failed to set the mouse sampling rate")
 }
 
 /// See [MousePacket] and its enum variants for further information.
-#[derive(Debug, TryFromPrimitive)]
+#[derive(Debug, Clone, Copy, TryFromPrimitive)]
 #[repr(u8)]
 pub enum MouseId {
     /// the mouse has no scroll-movement
@@ -672,7 +787,7 @@ pub enum MouseId {
 }
 
 /// set the [MouseId] by magic sequence
-pub fn set_mouse_id(id: MouseId) -> Result<(), &'static str> {
+pub fn set_mouse_id(id: MouseId) -> Result<(), Ps2Error> {
     disable_mouse_packet_streaming()?;
 
     use crate::ps2::SampleRate::*;
@@ -696,54 +811,45 @@ pub fn set_mouse_id(id: MouseId) -> Result<(), &'static str> {
 }
 
 /// get the [MouseId]
-pub fn mouse_id() -> Result<MouseId, &'static str> {
+pub fn mouse_id() -> Result<MouseId, Ps2Error> {
     disable_mouse_packet_streaming()?;
 
     command_to_mouse(HostToMouseCommandOrData::MouseCommand(GetDeviceID))?;
-    let id = read_data().try_into().map_err(|_| "failed to get mouse id: bad response")?;
+    let reply = polling_read()?;
+    let id = reply.try_into().map_err(|_| Ps2Error::BadResponse(reply))?;
 
     enable_mouse_packet_streaming()?;
     Ok(id)
 }
 
 /// reset the mouse
-pub fn reset_mouse() -> Result<(), &'static str> {
+pub fn reset_mouse() -> Result<(), Ps2Error> {
     command_to_mouse(HostToMouseCommandOrData::MouseCommand(Reset))?;
-    if let Ok(SelfTestPassed) = read_data().try_into() {
-        //returns mouse id 0
-        read_data();
-        return Ok(());
-    }
-    Err("failed to reset mouse")
+    read_self_test_result()?;
+    //returns mouse id 0
+    polling_read()?;
+    Ok(())
 }
 /// reset the keyboard
-pub fn reset_keyboard() -> Result<(), &'static str> {
+pub fn reset_keyboard() -> Result<(), Ps2Error> {
     command_to_keyboard(HostToKeyboardCommandOrData::KeyboardCommand(ResetAndStartSelfTest))?;
-    if let Ok(SelfTestPassed) = read_data().try_into() {
-        return Ok(())
-    }
-    Err("failed to reset keyboard")
+    read_self_test_result()
 }
 
 /// resend the most recent packet again
 #[allow(dead_code)]
-fn mouse_resend() -> Result<(), &'static str> {
+fn mouse_resend() -> Result<(), Ps2Error> {
     command_to_mouse(HostToMouseCommandOrData::MouseCommand(HostToMouseCommand::ResendByte))
-        .map_err(|_| "failed to resend mouse request")
 }
 
 /// enable the packet streaming
-fn enable_mouse_packet_streaming() -> Result<(), &'static str> {
-    command_to_mouse(HostToMouseCommandOrData::MouseCommand(EnableDataReporting)).map_err(|_| {
-        "failed to enable mouse streaming"
-    })
+fn enable_mouse_packet_streaming() -> Result<(), Ps2Error> {
+    command_to_mouse(HostToMouseCommandOrData::MouseCommand(EnableDataReporting))
 }
 
 /// disable the packet streaming
-fn disable_mouse_packet_streaming() -> Result<(), &'static str> {
-    command_to_mouse(HostToMouseCommandOrData::MouseCommand(DisableDataReporting)).map_err(|_| {
-        "failed to disable mouse streaming"
-    })
+fn disable_mouse_packet_streaming() -> Result<(), Ps2Error> {
+    command_to_mouse(HostToMouseCommandOrData::MouseCommand(DisableDataReporting))
 }
 
 #[derive(Clone)]
@@ -756,27 +862,136 @@ pub enum MouseResolution {
 
 /// set the resolution of the mouse
 #[allow(dead_code)]
-fn mouse_resolution(value: MouseResolution) -> Result<(), &'static str> {
+fn mouse_resolution(value: MouseResolution) -> Result<(), Ps2Error> {
     command_to_mouse(HostToMouseCommandOrData::MouseCommand(SetResolution))
         .and_then(|_| command_to_mouse(HostToMouseCommandOrData::MouseResolution(value)))
-        .map_err(|_| "failed to set the mouse resolution")
 }
 
+/// Where an update of the keyboard's LEDs is at. Its two bytes go out one at a time, each once the keyboard
+/// acknowledged the one before. The replies arrive through the keyboard's interrupt, see [take_led_reply].
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum LedStep {
+    Idle,
+    SentCommand,
+    SentState,
+}
+
+struct LedUpdate {
+    step: LedStep,
+    /// the [LEDState] to send after the command
+    wanted: LEDState,
+    /// whether `wanted` changed since it was sent, so it has to be sent again
+    outdated: bool,
+    /// tick the last byte was sent at, so a keyboard which never answers doesn't hold up later updates
+    sent_at: u64,
+    /// how often the keyboard asked for the last byte again
+    resends: u8,
+}
+
+impl LedUpdate {
+    /// send the byte which `step` is waiting for a reply to
+    fn send(&mut self, step: LedStep) -> Result<(), Ps2Error> {
+        let value = match step {
+            LedStep::Idle => {
+                self.step = LedStep::Idle;
+                return Ok(());
+            }
+            LedStep::SentCommand => HostToKeyboardCommandOrData::KeyboardCommand(SetLEDStatus),
+            LedStep::SentState => HostToKeyboardCommandOrData::LEDState(self.wanted.clone()),
+        };
+
+        self.step = step;
+        self.sent_at = time::ticks();
+
+        let result = polling_send(HostToDevice::Keyboard(value));
+        if result.is_err() {
+            self.step = LedStep::Idle;
+        }
+        result
+    }
+
+    fn is_stale(&self) -> bool {
+        time::ticks() > self.sent_at + time::ticks_in(TIMEOUT)
+    }
+}
+
+static LED_UPDATE: Mutex<LedUpdate> = Mutex::new(LedUpdate {
+    step: LedStep::Idle,
+    wanted: LEDState::new(),
+    outdated: false,
+    sent_at: 0,
+    resends: 0,
+});
+
 /// set LED status of the keyboard
-pub fn set_keyboard_led(value: LEDState) -> Result<(), &'static str> {
-    command_to_keyboard(HostToKeyboardCommandOrData::KeyboardCommand(SetLEDStatus))
-        .and_then(|_| command_to_keyboard(HostToKeyboardCommandOrData::LEDState(value)))
-        .map_err(|_| "failed to set the keyboard led")
+///
+/// Only sends the command, the state is sent by the keyboard's interrupt handler once it's acknowledged. If an
+/// update is still going on, `value` is sent after it instead.
+pub fn set_keyboard_led(value: LEDState) -> Result<(), Ps2Error> {
+    x86_64::instructions::interrupts::without_interrupts(|| {
+        let mut update = LED_UPDATE.lock();
+        update.wanted = value;
+
+        if update.step == LedStep::Idle || update.is_stale() {
+            update.outdated = false;
+            update.resends = 0;
+            update.send(LedStep::SentCommand)
+        } else {
+            update.outdated = true;
+            Ok(())
+        }
+    })
+}
+
+/// Called by the keyboard's interrupt handler with every byte it reads.
+/// Returns whether it was a reply to [set_keyboard_led], rather than a scan code.
+pub fn take_led_reply(byte: u8) -> bool {
+    let mut update = LED_UPDATE.lock();
+    if update.step == LedStep::Idle {
+        return false;
+    }
+
+    let result = match byte.try_into() {
+        Ok(Acknowledge) => {
+            update.resends = 0;
+
+            match update.step {
+                LedStep::SentCommand => update.send(LedStep::SentState),
+                _ if update.outdated => {
+                    update.outdated = false;
+                    update.send(LedStep::SentCommand)
+                }
+                _ => update.send(LedStep::Idle),
+            }
+        }
+        Ok(ResendCommand) if update.resends < RETRIES => {
+            update.resends += 1;
+
+            let step = update.step;
+            update.send(step)
+        }
+        Ok(ResendCommand) => {
+            update.step = LedStep::Idle;
+            Err(Ps2Error::Resend)
+        }
+        _ => return false,
+    };
+
+    if let Err(e) = result {
+        crate::serial_println!("WARN: Couldn't set the keyboard LEDs: {e}");
+    }
+
+    true
 }
 
 /// set the scancode set of the keyboard
-pub fn keyboard_scancode_set(value: ScancodeSet) -> Result<(), &'static str> {
+pub fn keyboard_scancode_set(value: ScancodeSet) -> Result<(), Ps2Error> {
     command_to_keyboard(HostToKeyboardCommandOrData::KeyboardCommand(SetScancodeSet))
         .and_then(|_| command_to_keyboard(HostToKeyboardCommandOrData::ScancodeSet(value)))
-        .map_err(|_| "failed to set the keyboard scancode set")
 }
 
 //NOTE: could be combined into a PS2DeviceType enum, see https://wiki.osdev.org/%228042%22_PS/2_Controller#Detecting_PS.2F2_Device_Types
+#[derive(Debug)]
 pub enum KeyboardType {
     MF2Keyboard,
     MF2KeyboardWithPSControllerTranslator,
@@ -786,26 +1001,181 @@ pub enum KeyboardType {
 /// detect the [KeyboardType]
 ///
 /// Note:
-/// On the identify command, [KeyboardType::AncientATKeyboard] usually returns no bytes at all, but [read_data] always returns a byte.
-/// This means [read_data] would return 0x00 here, even though the value is already reserved for the device type "Standard PS/2 mouse".
+/// On the identify command, [KeyboardType::AncientATKeyboard] usually returns no bytes at all, so a [Ps2Error::Timeout]
+/// waiting for one means it's that.
+/// 0x00 is also accepted for it, even though the value is already reserved for the device type "Standard PS/2 mouse".
 /// As we only care about detecting keyboard types here, it should work.
-pub fn keyboard_detect() -> Result<KeyboardType, &'static str> {
+pub fn keyboard_detect() -> Result<KeyboardType, Ps2Error> {
     command_to_keyboard(HostToKeyboardCommandOrData::KeyboardCommand(DisableScanning))?;
     command_to_keyboard(HostToKeyboardCommandOrData::KeyboardCommand(IdentifyKeyboard))?;
 
-    let keyboard_type = match read_data() {
-        0x00 => Ok(KeyboardType::AncientATKeyboard),
-        0xAB => {
-            match read_data() {
+    let keyboard_type = match polling_read() {
+        Ok(0x00) | Err(Ps2Error::Timeout) => Ok(KeyboardType::AncientATKeyboard),
+        Ok(0xAB) => {
+            match polling_read()? {
                 0x41 | 0xC1 => Ok(KeyboardType::MF2KeyboardWithPSControllerTranslator),
                 0x83 => Ok(KeyboardType::MF2Keyboard),
-                _ => Err("unrecognized keyboard type")
+                reply => Err(Ps2Error::BadResponse(reply))
             }
         }
-        _ => Err("unrecognized keyboard type")
+        Ok(reply) => Err(Ps2Error::BadResponse(reply)),
+        Err(e) => Err(e),
     };
 
     command_to_keyboard(HostToKeyboardCommandOrData::KeyboardCommand(EnableScanning))?;
 
     keyboard_type
-}
\ No newline at end of file
+}
+
+
+/// What was found on one of the controller's ports by [init].
+#[derive(Debug)]
+pub enum PortProbe<T> {
+    /// The controller only has one port.
+    Missing,
+    /// The port failed its interface test, or whatever is plugged in didn't reset and identify itself.
+    Failed(Ps2Error),
+    Device(T),
+}
+
+impl<T> PortProbe<T> {
+    pub fn device(&self) -> Option<&T> {
+        match self {
+            PortProbe::Device(device) => Some(device),
+            _ => None,
+        }
+    }
+}
+
+impl<T: fmt::Debug> fmt::Display for PortProbe<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PortProbe::Missing => write!(f, "no port"),
+            PortProbe::Failed(e) => write!(f, "{e}"),
+            PortProbe::Device(device) => write!(f, "{device:?}"),
+        }
+    }
+}
+
+/// What [init] found on the controller.
+#[derive(Debug)]
+pub struct Ps2Probe {
+    /// Whether the controller has a second port, which is where the mouse goes.
+    pub dual_channel: bool,
+    /// The device on the first port.
+    pub keyboard: PortProbe<KeyboardType>,
+    /// The device on the second port, with the [MouseId] it's using.
+    pub mouse: PortProbe<MouseId>,
+}
+
+impl fmt::Display for Ps2Probe {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "  Dual Channel: {}", if self.dual_channel { "yes" } else { "no" })?;
+        writeln!(f, "  Keyboard: {}", self.keyboard)?;
+        write!(f, "  Mouse: {}", self.mouse)
+    }
+}
+
+/// The [MouseId] of the mouse found by [init], which decides the size of its packets
+static MOUSE_ID: AtomicU8 = AtomicU8::new(MouseId::Zero as u8);
+
+/// Whether [init] is done. Until it is, whatever the controller sends is a reply to it, so interrupt handlers
+/// shouldn't read it
+static INITIALIZED: AtomicBool = AtomicBool::new(false);
+
+pub fn is_initialized() -> bool {
+    INITIALIZED.load(Ordering::Relaxed)
+}
+
+/// the [MouseId] to [read_mouse_packet] with
+pub fn packet_mouse_id() -> MouseId {
+    MOUSE_ID.load(Ordering::Relaxed).try_into().unwrap_or(MouseId::Zero)
+}
+
+/// Bring up the controller and whatever is plugged into it, following
+/// https://wiki.osdev.org/%228042%22_PS/2_Controller#Initialising_the_PS.2F2_Controller
+///
+/// Has to be called with interrupts enabled, as the timeouts are counted with the timer.
+/// Only fails if the controller itself doesn't work. Devices which don't work are reported in the [Ps2Probe],
+/// and interrupts are only enabled for the ports which have a working device.
+pub fn init() -> Result<Ps2Probe, Ps2Error> {
+    // disable the devices, so they don't send anything in the middle of this
+    write_command(DisablePort1)?;
+    write_command(DisablePort2)?;
+    flush_output_buffer();
+
+    // no interrupts until the devices are set up. the keyboard decoder wants scancode set 1, which needs translation
+    let mut config = read_config()?;
+    config.set_port1_interrupt_enabled(false);
+    config.set_port2_interrupt_enabled(false);
+    config.set_port1_translation_enabled(true);
+    write_config(config)?;
+
+    // some controllers reset their config on the self test, so it's written again
+    write_command(TestController)?;
+    read_controller_test_result()?;
+    write_config(config)?;
+
+    // the second port's clock can only be turned on if there is a second port
+    let dual_channel = config.port2_clock_disabled() && {
+        write_command(EnablePort2)?;
+        let enabled = !read_config()?.port2_clock_disabled();
+        write_command(DisablePort2)?;
+        enabled
+    };
+
+    let keyboard = match test_port(TestPort1, EnablePort1).and_then(|_| probe_keyboard()) {
+        Ok(keyboard) => PortProbe::Device(keyboard),
+        Err(e) => PortProbe::Failed(e),
+    };
+
+    let mouse = if dual_channel {
+        match test_port(TestPort2, EnablePort2).and_then(|_| probe_mouse()) {
+            Ok(id) => PortProbe::Device(id),
+            Err(e) => PortProbe::Failed(e),
+        }
+    } else {
+        PortProbe::Missing
+    };
+
+    if let Some(id) = mouse.device() {
+        MOUSE_ID.store(*id as u8, Ordering::Relaxed);
+    }
+
+    // replies to the commands above could still be waiting, which would otherwise be read as the first input
+    flush_output_buffer();
+
+    let mut config = read_config()?;
+    config.set_port1_interrupt_enabled(keyboard.device().is_some());
+    config.set_port2_interrupt_enabled(mouse.device().is_some());
+    write_config(config)?;
+
+    INITIALIZED.store(true, Ordering::Relaxed);
+
+    Ok(Ps2Probe { dual_channel, keyboard, mouse })
+}
+
+/// run a port's interface test, and turn it on if it passes
+fn test_port(test: HostToControllerCommand, enable: HostToControllerCommand) -> Result<(), Ps2Error> {
+    write_command(test)?;
+    match read_port_test_result()? {
+        PortTestResult::Passed => write_command(enable),
+        result => Err(Ps2Error::PortTestFailed(result)),
+    }
+}
+
+/// reset the keyboard and find out what it is
+fn probe_keyboard() -> Result<KeyboardType, Ps2Error> {
+    reset_keyboard()?;
+    keyboard_detect()
+}
+
+/// reset the mouse and ask it for scroll-movement and the extra buttons, returning the [MouseId] it ends up with
+fn probe_mouse() -> Result<MouseId, Ps2Error> {
+    reset_mouse()?;
+
+    // the mouse carries on with the [MouseId] it has if it doesn't support the others
+    let _ = set_mouse_id(MouseId::Four);
+
+    mouse_id()
+}
@@ -15,6 +15,8 @@ pub fn _print(args: ::core::fmt::Arguments) {
         SERIAL_PORT.lock()
             .write_fmt(args)
             .expect("Failed to write to serial port 1");
+
+        crate::display::framebuffer::mirror_serial(args);
     })
 }
 
@@ -0,0 +1,185 @@
+use core::time::Duration;
+
+use alloc::{sync::Arc, vec, vec::Vec};
+
+use crate::{allocator::{get_allocated, HEAP_SIZE}, display::framebuffer, font, interrupts, time};
+
+use super::Command;
+
+pub(super) fn all() -> Vec<Arc<dyn Command>> {
+    vec![
+        Arc::new(Help),
+        Arc::new(Clear),
+        Arc::new(Echo),
+        Arc::new(Mem),
+        Arc::new(Tasks),
+        Arc::new(SetFont),
+        Arc::new(Reboot),
+    ]
+}
+
+
+struct Help;
+
+impl Command for Help {
+    fn name(&self) -> &'static str {
+        "help"
+    }
+
+    fn description(&self) -> &'static str {
+        "List every available command"
+    }
+
+    fn run(&self, _: &[&str]) -> Result<(), &'static str> {
+        let commands = super::commands();
+        let width = commands.iter().map(|c| c.name().len()).max().unwrap_or_default();
+
+        for command in commands {
+            println!("{:width$}  {}", command.name(), command.description());
+        }
+
+        Ok(())
+    }
+}
+
+
+struct Clear;
+
+impl Command for Clear {
+    fn name(&self) -> &'static str {
+        "clear"
+    }
+
+    fn description(&self) -> &'static str {
+        "Clear the screen"
+    }
+
+    fn run(&self, _: &[&str]) -> Result<(), &'static str> {
+        framebuffer::clear();
+
+        Ok(())
+    }
+}
+
+
+struct Echo;
+
+impl Command for Echo {
+    fn name(&self) -> &'static str {
+        "echo"
+    }
+
+    fn description(&self) -> &'static str {
+        "Print the arguments"
+    }
+
+    fn run(&self, args: &[&str]) -> Result<(), &'static str> {
+        println!("{}", args.join(" "));
+
+        Ok(())
+    }
+}
+
+
+struct Mem;
+
+impl Command for Mem {
+    fn name(&self) -> &'static str {
+        "mem"
+    }
+
+    fn description(&self) -> &'static str {
+        "Show heap usage"
+    }
+
+    fn run(&self, _: &[&str]) -> Result<(), &'static str> {
+        let used = get_allocated();
+
+        println!(
+            "Heap: {} / {} KiB used ({}%)",
+            used / 1024,
+            HEAP_SIZE / 1024,
+            used * 100 / HEAP_SIZE,
+        );
+
+        Ok(())
+    }
+}
+
+
+struct Tasks;
+
+impl Command for Tasks {
+    fn name(&self) -> &'static str {
+        "tasks"
+    }
+
+    fn description(&self) -> &'static str {
+        "Show the number of running tasks"
+    }
+
+    fn run(&self, _: &[&str]) -> Result<(), &'static str> {
+        println!("Tasks: {}", crate::task::task_count());
+
+        Ok(())
+    }
+}
+
+
+struct SetFont;
+
+impl Command for SetFont {
+    fn name(&self) -> &'static str {
+        "font"
+    }
+
+    fn description(&self) -> &'static str {
+        "List the fonts, or switch with `font <name> [scale]`"
+    }
+
+    fn run(&self, args: &[&str]) -> Result<(), &'static str> {
+        let Some(name) = args.first() else {
+            println!("{}", font::names().join(" "));
+            return Ok(());
+        };
+
+        let font = font::get(name).ok_or("no font with that name")?;
+
+        let scale = match args.get(1) {
+            Some(scale) => scale.parse().ok().filter(|&scale| scale > 0).ok_or("scale must be a positive number")?,
+            None => 1,
+        };
+
+        framebuffer::set_font(font, scale);
+
+        Ok(())
+    }
+}
+
+
+/// How long [`Reboot`] gives the PS/2 controller to reset the CPU, before doing it another way.
+const RESET_WAIT: Duration = Duration::from_millis(500);
+
+struct Reboot;
+
+impl Command for Reboot {
+    fn name(&self) -> &'static str {
+        "reboot"
+    }
+
+    fn description(&self) -> &'static str {
+        "Restart the machine"
+    }
+
+    fn run(&self, _: &[&str]) -> Result<(), &'static str> {
+        crate::ps2::reset_cpu();
+
+        // The controller takes a moment to pull the reset line.
+        let deadline = time::ticks() + time::ticks_in(RESET_WAIT);
+        while time::ticks() <= deadline {
+            x86_64::instructions::hlt();
+        }
+
+        interrupts::triple_fault()
+    }
+}
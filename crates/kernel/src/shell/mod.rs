@@ -0,0 +1,123 @@
+//! A tiny command interpreter for lines entered in the console.
+//!
+//! Other kernel modules can add their own commands with [`register`].
+
+use alloc::{collections::BTreeMap, string::String, sync::Arc, vec::Vec};
+use spin::{Lazy, Mutex};
+
+mod builtin;
+
+static COMMANDS: Lazy<Mutex<BTreeMap<&'static str, Arc<dyn Command>>>> = Lazy::new(|| {
+    let mut commands = BTreeMap::new();
+
+    for command in builtin::all() {
+        commands.insert(command.name(), command);
+    }
+
+    Mutex::new(commands)
+});
+
+
+pub trait Command: Send + Sync {
+    /// What the command is invoked by. Must not contain whitespace.
+    fn name(&self) -> &'static str;
+
+    /// Single line summary shown by `help`.
+    fn description(&self) -> &'static str;
+
+    /// `args` excludes the command name.
+    fn run(&self, args: &[&str]) -> Result<(), &'static str>;
+}
+
+/// Registers a command, replacing any previous command with the same name.
+pub fn register(command: impl Command + 'static) {
+    COMMANDS.lock().insert(command.name(), Arc::new(command));
+}
+
+/// Returns every registered command sorted by name.
+pub fn commands() -> Vec<Arc<dyn Command>> {
+    COMMANDS.lock().values().cloned().collect()
+}
+
+/// Tokenizes and runs a single line of input.
+pub fn execute(line: &str) {
+    let tokens = tokenize(line);
+    let args = tokens.iter().map(String::as_str).collect::<Vec<_>>();
+
+    let Some((&name, args)) = args.split_first() else {
+        return;
+    };
+
+    // Cloned out so the registry isn't locked while the command runs.
+    let command = COMMANDS.lock().get(name).cloned();
+
+    match command {
+        Some(command) => if let Err(e) = command.run(args) {
+            println!("{name}: {e}");
+        }
+
+        None => println!("{name}: command not found"),
+    }
+}
+
+/// Splits a line on whitespace. Double quotes group words and `\` escapes the next character.
+pub fn tokenize(line: &str) -> Vec<String> {
+    let mut tokens = Vec::new();
+
+    let mut current = String::new();
+    let mut in_token = false;
+    let mut in_quotes = false;
+
+    let mut chars = line.chars();
+
+    while let Some(c) = chars.next() {
+        match c {
+            '\\' => {
+                if let Some(next) = chars.next() {
+                    current.push(next);
+                }
+
+                in_token = true;
+            }
+
+            '"' => {
+                in_quotes = !in_quotes;
+                in_token = true;
+            }
+
+            c if c.is_whitespace() && !in_quotes => {
+                if in_token {
+                    tokens.push(core::mem::take(&mut current));
+                    in_token = false;
+                }
+            }
+
+            c => {
+                current.push(c);
+                in_token = true;
+            }
+        }
+    }
+
+    if in_token {
+        tokens.push(current);
+    }
+
+    tokens
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test_case]
+    fn tokenize_whitespace() {
+        assert_eq!(tokenize("  echo  hello world "), ["echo", "hello", "world"]);
+    }
+
+    #[test_case]
+    fn tokenize_quotes_and_escapes() {
+        assert_eq!(tokenize(r#"echo "hello  world" \"hi\" """#), ["echo", "hello  world", "\"hi\"", ""]);
+    }
+}
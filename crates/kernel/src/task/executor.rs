@@ -1,8 +1,8 @@
 use alloc::{collections::BTreeMap, sync::Arc, task::Wake};
-use core::task::{Waker, Context, Poll};
+use core::{task::{Waker, Context, Poll}, sync::atomic::Ordering};
 use crossbeam_queue::ArrayQueue;
 
-use super::{Task, TaskId};
+use super::{Task, TaskId, TASK_COUNT};
 
 // TODO: Extend upon https://os.phil-opp.com/async-await/#possible-extensions
 
@@ -42,6 +42,8 @@ impl Executor {
         }
 
         self.task_queue.push(task_id).expect("queue full");
+
+        TASK_COUNT.fetch_add(1, Ordering::Relaxed);
     }
 
     fn run_ready_tasks(&mut self) {
@@ -69,6 +71,8 @@ impl Executor {
                     // task done -> remove it and its cached waker
                     tasks.remove(&task_id);
                     waker_cache.remove(&task_id);
+
+                    TASK_COUNT.fetch_sub(1, Ordering::Relaxed);
                 }
 
                 Poll::Pending => ()
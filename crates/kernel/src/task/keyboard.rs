@@ -1,10 +1,16 @@
 use core::{pin::Pin, task::{Context, Poll}};
 
+use common::user::LineEdit;
 use crossbeam_queue::ArrayQueue;
 use futures_util::{Stream, task::AtomicWaker, StreamExt};
-use keyboard::{ExtendedKeyCode, KeyCode, KeyEvent};
+use keyboard::{ExtendedKeyCode, KeyCode, KeyEvent, KeyInfo};
 use lazy_static::lazy_static;
 
+use crate::{
+    display::framebuffer::{cancel_search, edit_input, history_next, history_previous, reverse_search, scroll_view_pages, switch_console},
+    ps2::{self, LEDState},
+};
+
 static WAKER: AtomicWaker = AtomicWaker::new();
 
 lazy_static! {
@@ -22,10 +28,37 @@ pub async fn handle_key_presses() {
                 KeyCode::Unknown(v) => println!("[{v}]"),
                 KeyCode::Extended(ExtendedKeyCode::Unknown(v)) => println!("[e{v}]"),
 
-                KeyCode::Extended(ExtendedKeyCode::CursorUp) => input!("\x1B[1A"),
-                KeyCode::Extended(ExtendedKeyCode::CursorDown) => input!("\x1B[1B"),
-                KeyCode::Extended(ExtendedKeyCode::CursorRight) => input!("\x1B[1C"),
-                KeyCode::Extended(ExtendedKeyCode::CursorLeft) => input!("\x1B[1D"),
+                KeyCode::CapsLock | KeyCode::NumLock | KeyCode::ScrollLock if !key.is_held => update_leds(&key),
+                KeyCode::CapsLock | KeyCode::NumLock | KeyCode::ScrollLock => (),
+
+                KeyCode::Extended(ExtendedKeyCode::CursorUp) => history_previous(),
+                KeyCode::Extended(ExtendedKeyCode::CursorDown) => history_next(),
+                KeyCode::Extended(ExtendedKeyCode::CursorRight) if key.is_ctrl_down => edit_input(LineEdit::WordRight),
+                KeyCode::Extended(ExtendedKeyCode::CursorLeft) if key.is_ctrl_down => edit_input(LineEdit::WordLeft),
+                KeyCode::Extended(ExtendedKeyCode::CursorRight) => edit_input(LineEdit::Right),
+                KeyCode::Extended(ExtendedKeyCode::CursorLeft) => edit_input(LineEdit::Left),
+                KeyCode::Extended(ExtendedKeyCode::Home) => edit_input(LineEdit::Home),
+                KeyCode::Extended(ExtendedKeyCode::End) => edit_input(LineEdit::End),
+                KeyCode::Extended(ExtendedKeyCode::Delete) => edit_input(LineEdit::Delete),
+                KeyCode::Extended(ExtendedKeyCode::Insert) => (),
+                KeyCode::Extended(ExtendedKeyCode::PageUp) if key.is_shift_down => scroll_view_pages(1),
+                KeyCode::Extended(ExtendedKeyCode::PageDown) if key.is_shift_down => scroll_view_pages(-1),
+                KeyCode::Extended(ExtendedKeyCode::PageUp | ExtendedKeyCode::PageDown) => (),
+
+                KeyCode::F1 if key.is_alt_down => switch_console(0),
+                KeyCode::F2 if key.is_alt_down => switch_console(1),
+                KeyCode::F3 if key.is_alt_down => switch_console(2),
+                KeyCode::F4 if key.is_alt_down => switch_console(3),
+                KeyCode::F5 if key.is_alt_down => switch_console(4),
+                KeyCode::F6 if key.is_alt_down => switch_console(5),
+                KeyCode::F1 | KeyCode::F2 | KeyCode::F3 | KeyCode::F4 | KeyCode::F5 | KeyCode::F6
+                | KeyCode::F7 | KeyCode::F8 | KeyCode::F9 | KeyCode::F10 | KeyCode::F11 | KeyCode::F12 => (),
+
+                KeyCode::V if key.is_ctrl_down && key.is_shift_down => crate::clipboard::paste(),
+                KeyCode::U if key.is_ctrl_down => edit_input(LineEdit::KillToStart),
+                KeyCode::K if key.is_ctrl_down => edit_input(LineEdit::KillToEnd),
+                KeyCode::R if key.is_ctrl_down => reverse_search(),
+                KeyCode::G if key.is_ctrl_down => cancel_search(),
 
                 _ => input!("{}", key.char),
             }
@@ -34,6 +67,19 @@ pub async fn handle_key_presses() {
 }
 
 
+/// Light up the keyboard's LEDs for the locks which are on.
+fn update_leds(key: &KeyInfo) {
+    let leds = LEDState::new()
+        .with_caps_lock(key.is_caps_lock_enabled)
+        .with_number_lock(key.is_num_lock_enabled)
+        .with_scroll_lock(key.is_scroll_lock_enabled);
+
+    if let Err(e) = ps2::set_keyboard_led(leds) {
+        println!("WARNING: couldn't set the keyboard LEDs: {e}");
+    }
+}
+
+
 pub(crate) fn add_scancode(scancode: u8) {
     if SCANCODE_QUEUE.push(scancode).is_err() {
         println!("WARNING: scancode queue full; dropping keyboard input");
@@ -1,8 +1,10 @@
-use core::{future::Future, pin::Pin, task::{Context, Poll}, sync::atomic::{AtomicU64, Ordering}};
+use core::{future::Future, pin::Pin, task::{Context, Poll}, sync::atomic::{AtomicU64, AtomicUsize, Ordering}};
 use alloc::boxed::Box;
 
 pub mod keyboard;
+pub mod mouse;
 pub mod output;
+pub mod shell;
 mod executor;
 
 pub use executor::Executor;
@@ -15,6 +17,14 @@ pub use executor::Executor;
 // }
 
 
+/// Amount of tasks spawned on an [`Executor`] which haven't finished yet.
+static TASK_COUNT: AtomicUsize = AtomicUsize::new(0);
+
+pub fn task_count() -> usize {
+    TASK_COUNT.load(Ordering::Relaxed)
+}
+
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
 struct TaskId(u64);
 
@@ -0,0 +1,261 @@
+use core::{pin::Pin, task::{Context, Poll}};
+
+use crossbeam_queue::ArrayQueue;
+use futures_util::{Stream, task::AtomicWaker, StreamExt};
+use lazy_static::lazy_static;
+
+use crate::{
+    clipboard,
+    display::framebuffer::{copy_selection, extend_selection, move_pointer, screen_size, scroll_view, start_selection},
+    ps2::MousePacket,
+};
+
+/// Lines scrolled per notch of the wheel.
+const SCROLL_LINES: isize = 3;
+
+static WAKER: AtomicWaker = AtomicWaker::new();
+
+lazy_static! {
+    static ref PACKET_QUEUE: ArrayQueue<MousePacket> = ArrayQueue::new(100);
+}
+
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MouseButton {
+    Left,
+    Right,
+    Middle,
+    Fourth,
+    Fifth,
+}
+
+impl MouseButton {
+    const ALL: [MouseButton; 5] = [Self::Left, Self::Right, Self::Middle, Self::Fourth, Self::Fifth];
+
+    fn is_down(self, packet: &MousePacket) -> bool {
+        match self {
+            Self::Left => packet.button_left(),
+            Self::Right => packet.button_right(),
+            Self::Middle => packet.button_middle(),
+            Self::Fourth => packet.button_4(),
+            Self::Fifth => packet.button_5(),
+        }
+    }
+}
+
+/// Positions are in pixels, from the top left of the screen.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MouseEvent {
+    Move { position: (usize, usize) },
+    Press { button: MouseButton, position: (usize, usize) },
+    Release { button: MouseButton, position: (usize, usize) },
+    /// Positive when the wheel is turned towards the user.
+    Scroll { notches: i8 },
+}
+
+
+/// Turns the relative movement of packets into an absolute position, and button states into presses and releases.
+pub struct Mouse {
+    position: (usize, usize),
+    /// The position is kept within `(0, 0)` and this, exclusive.
+    bounds: (usize, usize),
+    /// Bit per button, in the order of [`MouseButton::ALL`].
+    buttons: u8,
+}
+
+impl Mouse {
+    /// Starts in the middle of `bounds`.
+    pub fn new(bounds: (usize, usize)) -> Self {
+        Self {
+            position: (bounds.0 / 2, bounds.1 / 2),
+            bounds,
+            buttons: 0,
+        }
+    }
+
+    pub fn position(&self) -> (usize, usize) {
+        self.position
+    }
+
+    /// Calls `f` with every event caused by `packet`: the move first, so presses happen where the pointer ends up.
+    pub fn update(&mut self, packet: &MousePacket, mut f: impl FnMut(MouseEvent)) {
+        let (dx, dy) = (packet.x_movement() as isize, packet.y_movement() as isize);
+
+        if dx != 0 || dy != 0 {
+            // Upwards is positive for the mouse, but downwards on screen.
+            self.position = (
+                clamp(self.position.0, dx, self.bounds.0),
+                clamp(self.position.1, -dy, self.bounds.1),
+            );
+
+            f(MouseEvent::Move { position: self.position });
+        }
+
+        for (bit, button) in MouseButton::ALL.into_iter().enumerate() {
+            let (was_down, is_down) = (self.buttons & (1 << bit) != 0, button.is_down(packet));
+
+            match (was_down, is_down) {
+                (false, true) => f(MouseEvent::Press { button, position: self.position }),
+                (true, false) => f(MouseEvent::Release { button, position: self.position }),
+                _ => continue,
+            }
+
+            self.buttons ^= 1 << bit;
+        }
+
+        if packet.scroll_movement() != 0 {
+            f(MouseEvent::Scroll { notches: packet.scroll_movement() });
+        }
+    }
+}
+
+fn clamp(value: usize, delta: isize, bound: usize) -> usize {
+    value.saturating_add_signed(delta).min(bound.saturating_sub(1))
+}
+
+
+pub async fn handle_mouse() {
+    let mut packets = MousePacketStream::new();
+    let mut mouse = Mouse::new(screen_size().unwrap_or_default());
+    // Whether the left button is being held down to select output.
+    let mut selecting = false;
+
+    // Will never return None
+    while let Some(packet) = packets.next().await {
+        mouse.update(&packet, |event| match event {
+            MouseEvent::Move { position } => {
+                move_pointer(position);
+
+                if selecting {
+                    extend_selection(position);
+                }
+            }
+
+            MouseEvent::Press { button: MouseButton::Left, position } => {
+                selecting = true;
+                start_selection(position);
+            }
+            MouseEvent::Release { button: MouseButton::Left, .. } => {
+                selecting = false;
+                copy_selection();
+            }
+            MouseEvent::Press { button: MouseButton::Middle, .. } => clipboard::paste(),
+
+            MouseEvent::Scroll { notches } => scroll_view(-(notches as isize) * SCROLL_LINES),
+            MouseEvent::Press { .. } | MouseEvent::Release { .. } => (),
+        });
+    }
+}
+
+
+pub(crate) fn add_packet(packet: MousePacket) {
+    if PACKET_QUEUE.push(packet).is_err() {
+        println!("WARNING: mouse packet queue full; dropping mouse input");
+    } else {
+        WAKER.wake();
+    }
+}
+
+
+pub struct MousePacketStream {
+    _private: (),
+}
+
+impl MousePacketStream {
+    #[allow(clippy::new_without_default)]
+    pub fn new() -> Self {
+        // Initiates the field.
+        let _ = PACKET_QUEUE.len();
+        MousePacketStream { _private: () }
+    }
+}
+
+impl Stream for MousePacketStream {
+    type Item = MousePacket;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context) -> Poll<Option<MousePacket>> {
+        if let Some(packet) = PACKET_QUEUE.pop() {
+            return Poll::Ready(Some(packet));
+        }
+
+        WAKER.register(cx.waker());
+
+        match PACKET_QUEUE.pop() {
+            Some(packet) => {
+                WAKER.take();
+
+                Poll::Ready(Some(packet))
+            }
+
+            None => Poll::Pending,
+        }
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec::Vec;
+
+    use super::*;
+    use crate::ps2::{MouseId, MousePacketGeneric, MousePacketReader};
+
+    /// A packet without a scroll wheel. Movement is limited to what fits in a byte.
+    fn packet(buttons: u8, dx: i8, dy: i8) -> MousePacket {
+        let sign = |value: i8, bit: u8| if value < 0 { 1 << bit } else { 0 };
+        let flags = buttons | 0b1000 | sign(dx, 4) | sign(dy, 5);
+
+        MousePacket::Zero(MousePacketGeneric::from_bytes([flags, dx as u8, dy as u8]))
+    }
+
+    fn events(mouse: &mut Mouse, packet: MousePacket) -> Vec<MouseEvent> {
+        let mut events = Vec::new();
+        mouse.update(&packet, |event| events.push(event));
+        events
+    }
+
+    #[test_case]
+    fn position_is_clamped() {
+        let mut mouse = Mouse::new((100, 50));
+        assert_eq!(mouse.position(), (50, 25));
+
+        assert_eq!(events(&mut mouse, packet(0, 10, 5)), [MouseEvent::Move { position: (60, 20) }]);
+
+        events(&mut mouse, packet(0, 127, -128));
+        assert_eq!(mouse.position(), (99, 49));
+
+        events(&mut mouse, packet(0, -128, 127));
+        assert_eq!(mouse.position(), (0, 0));
+    }
+
+    #[test_case]
+    fn packets_resync_on_their_first_byte() {
+        let mut reader = MousePacketReader::new();
+        let bytes = |packet: MousePacket| match packet {
+            MousePacket::Zero(packet) => packet.into_bytes(),
+            _ => panic!("expected a packet without a scroll wheel"),
+        };
+
+        // The end of a packet whose start was lost. Neither byte can start one.
+        assert!(reader.push(0x05, MouseId::Zero).is_none());
+        assert!(reader.push(0x03, MouseId::Zero).is_none());
+
+        assert!(reader.push(0b1001, MouseId::Zero).is_none());
+        assert!(reader.push(0x05, MouseId::Zero).is_none());
+        assert_eq!(reader.push(0x03, MouseId::Zero).map(bytes), Some([0b1001, 0x05, 0x03]));
+    }
+
+    #[test_case]
+    fn presses_and_releases() {
+        let mut mouse = Mouse::new((100, 50));
+        let (left, right) = (MouseButton::Left, MouseButton::Right);
+
+        assert_eq!(events(&mut mouse, packet(0b01, 0, 0)), [MouseEvent::Press { button: left, position: (50, 25) }]);
+        assert_eq!(events(&mut mouse, packet(0b01, 0, 0)), []);
+        assert_eq!(events(&mut mouse, packet(0b10, 1, 0)), [
+            MouseEvent::Move { position: (51, 25) },
+            MouseEvent::Release { button: left, position: (51, 25) },
+            MouseEvent::Press { button: right, position: (51, 25) },
+        ]);
+    }
+}
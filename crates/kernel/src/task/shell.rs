@@ -0,0 +1,68 @@
+use core::{pin::Pin, task::{Context, Poll}};
+
+use alloc::string::String;
+use crossbeam_queue::ArrayQueue;
+use futures_util::{Stream, task::AtomicWaker, StreamExt};
+use lazy_static::lazy_static;
+
+static WAKER: AtomicWaker = AtomicWaker::new();
+
+lazy_static! {
+    static ref COMMAND_QUEUE: ArrayQueue<String> = ArrayQueue::new(20);
+}
+
+
+pub async fn handle_commands() {
+    let mut commands = CommandStream::new();
+
+    // Will never return None
+    while let Some(line) = commands.next().await {
+        crate::shell::execute(&line);
+    }
+}
+
+
+/// Queues a line the user submitted to be run by the shell.
+pub(crate) fn add_command(line: String) {
+    if COMMAND_QUEUE.push(line).is_err() {
+        println!("WARNING: command queue full; dropping command");
+    } else {
+        WAKER.wake();
+    }
+}
+
+
+pub struct CommandStream {
+    _private: (),
+}
+
+impl CommandStream {
+    #[allow(clippy::new_without_default)]
+    pub fn new() -> Self {
+        // Initiates the field.
+        let _ = COMMAND_QUEUE.len();
+        CommandStream { _private: () }
+    }
+}
+
+impl Stream for CommandStream {
+    type Item = String;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context) -> Poll<Option<Self::Item>> {
+        if let Some(line) = COMMAND_QUEUE.pop() {
+            return Poll::Ready(Some(line));
+        }
+
+        WAKER.register(cx.waker());
+
+        match COMMAND_QUEUE.pop() {
+            Some(line) => {
+                WAKER.take();
+
+                Poll::Ready(Some(line))
+            }
+
+            None => Poll::Pending,
+        }
+    }
+}
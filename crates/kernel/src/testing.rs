@@ -0,0 +1,88 @@
+//! Plumbing for the custom test framework.
+//!
+//! Tests are run inside QEMU by the `boot` crate, which attaches an `isa-debug-exit`
+//! device at [`QEMU_EXIT_PORT`] and reports results written to the serial port.
+
+use core::{panic::PanicInfo, sync::atomic::{AtomicU64, Ordering}};
+
+use x86_64::instructions::port::Port;
+
+use crate::{serial_print, serial_println, hlt_loop};
+
+/// I/O port of the `isa-debug-exit` device. Must match the `iobase` passed to QEMU.
+pub const QEMU_EXIT_PORT: u16 = 0xF4;
+
+/// Amount of timer ticks a single test may run for. ~30 seconds, see `ConsoleCursor::update`.
+pub const TEST_TIMEOUT_TICKS: u64 = 2_000;
+
+/// Remaining ticks for the currently running test. Zero when no test is running.
+static TEST_TICKS_REMAINING: AtomicU64 = AtomicU64::new(0);
+
+/// QEMU exits with `(code << 1) | 1`, so neither value can collide with QEMU's own exit codes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[repr(u32)]
+pub enum QemuExitCode {
+    Success = 0x10,
+    Failed = 0x11,
+}
+
+pub fn exit_qemu(exit_code: QemuExitCode) {
+    unsafe {
+        Port::new(QEMU_EXIT_PORT).write(exit_code as u32);
+    }
+}
+
+
+pub trait Testable {
+    fn run(&self);
+}
+
+impl<T: Fn()> Testable for T {
+    fn run(&self) {
+        serial_print!("{}...\t", core::any::type_name::<T>());
+
+        TEST_TICKS_REMAINING.store(TEST_TIMEOUT_TICKS, Ordering::SeqCst);
+        self();
+        TEST_TICKS_REMAINING.store(0, Ordering::SeqCst);
+
+        serial_println!("[ok]");
+    }
+}
+
+pub fn test_runner(tests: &[&dyn Testable]) {
+    serial_println!("Running {} tests", tests.len());
+
+    for test in tests {
+        test.run();
+    }
+
+    exit_qemu(QemuExitCode::Success);
+}
+
+pub fn test_panic_handler(info: &PanicInfo) -> ! {
+    serial_println!("[failed]\n");
+    serial_println!("Error: {info}\n");
+
+    exit_qemu(QemuExitCode::Failed);
+
+    hlt_loop()
+}
+
+/// Called on every timer interrupt. Fails the running test once it exceeds [`TEST_TIMEOUT_TICKS`].
+///
+/// Only has an effect once the APIC timer has been started with [`crate::init`].
+pub(crate) fn tick() {
+    let remaining = TEST_TICKS_REMAINING.load(Ordering::SeqCst);
+
+    if remaining == 0 {
+        return;
+    }
+
+    if remaining == 1 {
+        serial_println!("[timeout]\n");
+
+        exit_qemu(QemuExitCode::Failed);
+    }
+
+    TEST_TICKS_REMAINING.store(remaining - 1, Ordering::SeqCst);
+}
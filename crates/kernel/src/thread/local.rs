@@ -15,11 +15,15 @@ pub struct LocalKey<T: 'static> {
     // trivially devirtualizable by LLVM because the value of `inner` never
     // changes and the constant should be readonly within a crate. This mainly
     // only runs into problems when TLS statics are exported across crates.
+    #[allow(dead_code)]
     inner: unsafe fn(Option<&mut Option<T>>) -> Option<&'static T>,
 }
 
 
 impl<T: 'static> LocalKey<T> {
+    /// # Safety
+    ///
+    /// `inner` must return a reference which is valid for the lifetime of the current thread.
     pub const unsafe fn new(
         inner: unsafe fn(Option<&mut Option<T>>) -> Option<&'static T>,
     ) -> LocalKey<T> {
@@ -40,7 +40,7 @@ where
     let f = MaybeDangling::new(function);
 
     let main = move || {
-        let f = f.into_inner();
+        let _f = f.into_inner();
     };
 
     ThreadRoll {
@@ -54,6 +54,7 @@ where
 struct ThreadId(u64);
 
 impl ThreadId {
+    #[allow(dead_code)]
     fn new() -> Self {
         static NEXT_ID: AtomicU64 = AtomicU64::new(0);
         ThreadId(NEXT_ID.fetch_add(1, Ordering::SeqCst))
@@ -66,5 +67,6 @@ pub struct Thread {
 }
 
 pub struct ThreadRoll {
+    #[allow(dead_code)]
     native: Box<dyn FnOnce() + 'static>,
 }
\ No newline at end of file
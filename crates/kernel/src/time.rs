@@ -0,0 +1,27 @@
+//! Uptime, counted in timer interrupts.
+
+use core::{sync::atomic::{AtomicU64, Ordering}, time::Duration};
+
+/// How often the timer fires, which `apic::init` calibrates it to. It's about what it used to fire at under QEMU,
+/// which other timings are tuned for. See `testing::TEST_TIMEOUT_TICKS`.
+pub const TICKS_PER_SECOND: u64 = 66;
+
+static TICKS: AtomicU64 = AtomicU64::new(0);
+
+/// Called on every timer interrupt.
+pub(crate) fn tick() {
+    TICKS.fetch_add(1, Ordering::Relaxed);
+}
+
+pub fn ticks() -> u64 {
+    TICKS.load(Ordering::Relaxed)
+}
+
+pub fn uptime() -> Duration {
+    Duration::from_millis(ticks() * 1000 / TICKS_PER_SECOND)
+}
+
+/// Ticks which pass in at least `duration`. One extra, as the current tick may be almost over.
+pub fn ticks_in(duration: Duration) -> u64 {
+    duration.as_millis() as u64 * TICKS_PER_SECOND / 1000 + 1
+}
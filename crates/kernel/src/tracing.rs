@@ -4,7 +4,7 @@ use core::fmt::Write;
 
 use tracing::{Subscriber, Metadata, span, field::Visit, Level};
 
-use crate::{serial_println, Locked, color::ColorExt};
+use crate::{log_println, serial_println, Locked, color::ColorExt};
 
 pub fn init_tracing() {
     tracing::subscriber::set_global_default(Locked::new(KernelTracingSubscriber::new()))
@@ -146,7 +146,7 @@ impl Subscriber for Locked<KernelTracingSubscriber> {
         let sub = self.lock();
 
         if let Some(span_id) = sub.current_span && let Some(span) = sub.spans.get(&span_id) {
-            println!("{span}{event_info}");
+            log_println!("{span}{event_info}");
         } else {
             // TODO: abstract
             let meta = event.metadata();
@@ -165,7 +165,7 @@ impl Subscriber for Locked<KernelTracingSubscriber> {
             let line = meta.line().unwrap_or(0).to_string();
             let line = line.fg(level_color);
 
-            println!("[{level} {file}:{line}] {event_info}");
+            log_println!("[{level} {file}:{line}] {event_info}");
         }
     }
 
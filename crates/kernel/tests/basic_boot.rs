@@ -4,18 +4,21 @@
 #![test_runner(kernel::test_runner)]
 #![reexport_test_harness_main = "test_main"]
 
-#![allow(
-	clippy::empty_loop
-)]
-
+#[macro_use] extern crate gbl;
 
 use core::panic::PanicInfo;
 
-#[no_mangle] // don't mangle the name of this function
-pub extern "C" fn _start() -> ! {
+use bootloader_api::{entry_point, BootInfo};
+
+
+entry_point!(main, config = &kernel::BOOTLOADER_CONFIG);
+
+fn main(boot_info: &'static mut BootInfo) -> ! {
+    kernel::init(boot_info);
+
     test_main();
 
-    loop {}
+    kernel::hlt_loop()
 }
 
 #[panic_handler]
@@ -29,5 +32,5 @@ fn panic(info: &PanicInfo) -> ! {
 
 #[test_case]
 fn test_println() {
-    log::debug!("test_println output");
-}
\ No newline at end of file
+    println!("test_println output");
+}
@@ -1,37 +1,23 @@
-
 #![no_std]
 #![no_main]
 
-#![allow(
-	clippy::empty_loop
-)]
-
 
 use core::panic::PanicInfo;
+
+use bootloader_api::{entry_point, BootInfo};
 use kernel::{QemuExitCode, exit_qemu, serial_println, serial_print};
 
 
-#[no_mangle]
-pub extern "C" fn _start() -> ! {
+entry_point!(main, config = &kernel::BOOTLOADER_CONFIG);
+
+fn main(_boot_info: &'static mut BootInfo) -> ! {
     should_fail();
 
     serial_println!("[test did not panic]");
 
-	exit_qemu(QemuExitCode::Failed);
-
-    loop {}
-}
-
-pub fn test_runner(tests: &[&dyn Fn()]) {
-    serial_println!("Running {} tests", tests.len());
-
-    for test in tests {
-        test();
-        serial_println!("[test did not panic]");
-        exit_qemu(QemuExitCode::Failed);
-    }
+    exit_qemu(QemuExitCode::Failed);
 
-    exit_qemu(QemuExitCode::Success);
+    kernel::hlt_loop()
 }
 
 
@@ -40,11 +26,11 @@ pub fn test_runner(tests: &[&dyn Fn()]) {
 fn panic(_info: &PanicInfo) -> ! {
     serial_println!("[ok]");
     exit_qemu(QemuExitCode::Success);
-    loop {}
+    kernel::hlt_loop()
 }
 
 
 fn should_fail() {
     serial_print!("should_panic::should_fail...\t");
     assert_eq!(0, 1);
-}
\ No newline at end of file
+}
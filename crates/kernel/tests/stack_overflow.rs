@@ -2,13 +2,11 @@
 #![no_std]
 #![no_main]
 
-#![allow(
-	unconditional_recursion,
-	clippy::empty_loop
-)]
+#![allow(unconditional_recursion)]
 
 use core::panic::PanicInfo;
 
+use bootloader_api::{entry_point, BootInfo};
 use lazy_static::lazy_static;
 use x86_64::structures::idt::{InterruptDescriptorTable, InterruptStackFrame};
 
@@ -36,7 +34,7 @@ extern "x86-interrupt" fn test_double_fault_handler(
 ) -> ! {
 	serial_println!("[ok]");
 	exit_qemu(QemuExitCode::Success);
-	loop {}
+	kernel::hlt_loop()
 }
 
 
@@ -45,11 +43,12 @@ pub fn init_test_idt() {
 }
 
 
-#[no_mangle]
-pub extern "C" fn _start() -> ! {
+entry_point!(main, config = &kernel::BOOTLOADER_CONFIG);
+
+fn main(_boot_info: &'static mut BootInfo) -> ! {
 	serial_print!("stack_overflow::stack_overflow...\t");
 
-	gdt::init();
+	unsafe { gdt::init(); }
 	init_test_idt();
 
 	// trigger a stack overflow
@@ -61,7 +60,7 @@ pub extern "C" fn _start() -> ! {
 
 fn stack_overflow() {
 	stack_overflow(); // for each recursion, the return address is pushed
-	volatile::Volatile::new(0).read(); // prevent tail recursion optimizations
+	unsafe { core::ptr::read_volatile(&0) }; // prevent tail recursion optimizations
 }
 
 
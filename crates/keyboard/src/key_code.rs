@@ -91,6 +91,23 @@ pub enum KeyCode {
     F11,
     F12,
 
+    NumLock,
+    ScrollLock,
+
+    Keypad7,
+    Keypad8,
+    Keypad9,
+    KeypadMinus,
+    Keypad4,
+    Keypad5,
+    Keypad6,
+    KeypadPlus,
+    Keypad1,
+    Keypad2,
+    Keypad3,
+    Keypad0,
+    KeypadPeriod,
+
     // NumPadAsterisk = 55,
     // NumPadHome = 71,
     // NumPadArrowUp,
@@ -201,20 +218,61 @@ impl KeyCode {
             66 => F8,
             67 => F9,
             68 => F10,
-            // 69...132
-            133 => F11,
-            134 => F12,
+            69 => NumLock,
+            70 => ScrollLock,
+
+            71 => Keypad7,
+            72 => Keypad8,
+            73 => Keypad9,
+            74 => KeypadMinus,
+            75 => Keypad4,
+            76 => Keypad5,
+            77 => Keypad6,
+            78 => KeypadPlus,
+            79 => Keypad1,
+            80 => Keypad2,
+            81 => Keypad3,
+            82 => Keypad0,
+            83 => KeypadPeriod,
+
+            87 => F11,
+            88 => F12,
 
             _ => Unknown(value),
         }
     }
 
+    /// The key printed under a keypad key, which it stands for while Num Lock is off.
+    /// `None` for other keys, and for keypad 5 which has none.
+    pub fn keypad_navigation(self) -> Option<ExtendedKeyCode> {
+        use ExtendedKeyCode::*;
+
+        Some(match self {
+            KeyCode::Keypad7 => Home,
+            KeyCode::Keypad8 => CursorUp,
+            KeyCode::Keypad9 => PageUp,
+            KeyCode::Keypad4 => CursorLeft,
+            KeyCode::Keypad6 => CursorRight,
+            KeyCode::Keypad1 => End,
+            KeyCode::Keypad2 => CursorDown,
+            KeyCode::Keypad3 => PageDown,
+            KeyCode::Keypad0 => Insert,
+            KeyCode::KeypadPeriod => Delete,
+
+            _ => return None,
+        })
+    }
+
+    /// Whether Num Lock decides what this key does.
+    pub fn is_keypad_digit(self) -> bool {
+        self == KeyCode::Keypad5 || self.keypad_navigation().is_some()
+    }
+
     pub fn to_byte_unicode(self, use_alternative: bool) -> Option<u8> {
         Some(match self {
             KeyCode::Unknown(_) => 0,
-            KeyCode::Extended(ExtendedKeyCode::Unknown(_)) => 0,
+            KeyCode::Extended(ext) => return ext.to_byte_unicode(),
 
-            KeyCode::Extended(_) => return None,
             KeyCode::Escape => return None,
             KeyCode::LeftControl => return None,
             KeyCode::RightShift => return None,
@@ -222,20 +280,23 @@ impl KeyCode {
             KeyCode::LeftCommand => return None,
             KeyCode::LeftTab => return None,
             KeyCode::LeftShift => return None,
-            KeyCode::CapsLock => return None,
-
-            KeyCode::F1 => return None,
-            KeyCode::F2 => return None,
-            KeyCode::F3 => return None,
-            KeyCode::F4 => return None,
-            KeyCode::F5 => return None,
-            KeyCode::F6 => return None,
-            KeyCode::F7 => return None,
-            KeyCode::F8 => return None,
-            KeyCode::F9 => return None,
-            KeyCode::F10 => return None,
-            KeyCode::F11 => return None,
-            KeyCode::F12 => return None,
+
+            KeyCode::CapsLock => 0,
+            KeyCode::NumLock => 0,
+            KeyCode::ScrollLock => 0,
+
+            KeyCode::F1 => 0,
+            KeyCode::F2 => 0,
+            KeyCode::F3 => 0,
+            KeyCode::F4 => 0,
+            KeyCode::F5 => 0,
+            KeyCode::F6 => 0,
+            KeyCode::F7 => 0,
+            KeyCode::F8 => 0,
+            KeyCode::F9 => 0,
+            KeyCode::F10 => 0,
+            KeyCode::F11 => 0,
+            KeyCode::F12 => 0,
 
             KeyCode::Backspace => 8,
             KeyCode::Enter => 10,
@@ -288,6 +349,20 @@ impl KeyCode {
             KeyCode::Y => if use_alternative { 89 } else { 121 },
             KeyCode::Z => if use_alternative { 90 } else { 122 },
 
+            KeyCode::Keypad0 => 48,
+            KeyCode::Keypad1 => 49,
+            KeyCode::Keypad2 => 50,
+            KeyCode::Keypad3 => 51,
+            KeyCode::Keypad4 => 52,
+            KeyCode::Keypad5 => 53,
+            KeyCode::Keypad6 => 54,
+            KeyCode::Keypad7 => 55,
+            KeyCode::Keypad8 => 56,
+            KeyCode::Keypad9 => 57,
+            KeyCode::KeypadPeriod => 46,
+            KeyCode::KeypadMinus => 45,
+            KeyCode::KeypadPlus => 43,
+
         })
     }
 }
@@ -404,6 +479,13 @@ impl ExtendedKeyCode {
             ExtendedKeyCode::CursorRight => 0,
             ExtendedKeyCode::CursorDown => 0,
 
+            ExtendedKeyCode::Home => 0,
+            ExtendedKeyCode::End => 0,
+            ExtendedKeyCode::PageUp => 0,
+            ExtendedKeyCode::PageDown => 0,
+            ExtendedKeyCode::Insert => 0,
+            ExtendedKeyCode::Delete => 0,
+
             _ => return None,
         })
     }
@@ -6,7 +6,7 @@
 
 extern crate alloc;
 
-use alloc::collections::BTreeSet;
+use alloc::collections::BTreeMap;
 use lazy_static::lazy_static;
 use spin::Mutex;
 
@@ -16,13 +16,16 @@ pub use key_code::*;
 
 
 lazy_static! {
-    static ref KEYS_DOWN: Mutex<BTreeSet<KeyCode>> = Mutex::new(BTreeSet::default());
+    /// Keys which are down, with what they were translated to when pressed. See [`KeyboardInfo::translate`].
+    static ref KEYS_DOWN: Mutex<BTreeMap<KeyCode, Option<KeyCode>>> = Mutex::new(BTreeMap::default());
     static ref INFO: Mutex<KeyboardInfo> = Mutex::new(KeyboardInfo::default());
 }
 
 #[derive(Default)]
 struct KeyboardInfo {
     is_caps_lock_enabled: bool,
+    is_num_lock_enabled: bool,
+    is_scroll_lock_enabled: bool,
     is_shift_down: bool,
     is_ctrl_down: bool,
     is_alt_down: bool,
@@ -30,6 +33,37 @@ struct KeyboardInfo {
     ext: ScanCodeExtension,
 }
 
+impl KeyboardInfo {
+    /// The key a keypad key stands for: itself with Num Lock on, the navigation key printed under it otherwise.
+    /// `None` for keypad 5 without Num Lock, which does nothing. Other keys stand for themselves.
+    ///
+    /// Keys are translated when they're pressed, and go up as what they went down as, even if Num Lock changed.
+    fn translate(&self, key_code: KeyCode) -> Option<KeyCode> {
+        // Shift does the opposite of what Num Lock says, like it does with Caps Lock.
+        if key_code.is_keypad_digit() && !(self.is_num_lock_enabled ^ self.is_shift_down) {
+            key_code.keypad_navigation().map(KeyCode::Extended)
+        } else {
+            Some(key_code)
+        }
+    }
+
+    fn key_info(&self, code: KeyCode, is_held: bool) -> Option<KeyInfo> {
+        Some(KeyInfo {
+            is_caps_lock_enabled: self.is_caps_lock_enabled,
+            is_num_lock_enabled: self.is_num_lock_enabled,
+            is_scroll_lock_enabled: self.is_scroll_lock_enabled,
+            is_shift_down: self.is_shift_down,
+            is_ctrl_down: self.is_ctrl_down,
+            is_alt_down: self.is_alt_down,
+
+            is_held,
+
+            char: code.to_byte_unicode(self.is_caps_lock_enabled ^ self.is_shift_down)? as char,
+            code,
+        })
+    }
+}
+
 const START_EXPANDED_CODE_SPACE: u8 = 0xE0;
 
 // Only care about < 0x58 for now.
@@ -46,7 +80,10 @@ pub fn handle_next_scan_code(value: u8) -> Option<KeyEvent> {
         // Release
         let key_code = KeyCode::from_scan_code(info.ext, value - 128);
 
-        KEYS_DOWN.lock().remove(&key_code);
+        info.ext = ScanCodeExtension::Default;
+
+        // A release without a press is translated as it is now.
+        let code = KEYS_DOWN.lock().remove(&key_code).unwrap_or_else(|| info.translate(key_code));
 
         match key_code {
             KeyCode::LeftShift | KeyCode::RightShift => {
@@ -57,20 +94,14 @@ pub fn handle_next_scan_code(value: u8) -> Option<KeyEvent> {
                 info.is_ctrl_down = false;
             }
 
+            KeyCode::LeftCommand | KeyCode::Extended(ExtendedKeyCode::RightAlt) => {
+                info.is_alt_down = false;
+            }
+
             _ => ()
         }
 
-        Some(KeyEvent::Up(KeyInfo {
-            is_caps_lock_enabled: info.is_caps_lock_enabled,
-            is_shift_down: info.is_shift_down,
-            is_ctrl_down: info.is_ctrl_down,
-            is_alt_down: info.is_alt_down,
-
-            is_held: false,
-
-            char: key_code.to_byte_unicode(info.is_caps_lock_enabled ^ info.is_shift_down)? as char,
-            code: key_code,
-        }))
+        info.key_info(code?, false).map(KeyEvent::Up)
     } else {
         let key_code = KeyCode::from_scan_code(info.ext, value);
 
@@ -91,7 +122,7 @@ pub fn handle_next_scan_code(value: u8) -> Option<KeyEvent> {
         // KeyCode::F11 => return None,
         // KeyCode::F12 => return None,
 
-        let is_held = !KEYS_DOWN.lock().insert(key_code);
+        let is_held = KEYS_DOWN.lock().contains_key(&key_code);
 
         match key_code {
             KeyCode::LeftShift | KeyCode::RightShift => {
@@ -102,26 +133,30 @@ pub fn handle_next_scan_code(value: u8) -> Option<KeyEvent> {
                 info.is_ctrl_down = true;
             }
 
+            KeyCode::LeftCommand | KeyCode::Extended(ExtendedKeyCode::RightAlt) => {
+                info.is_alt_down = true;
+            }
+
             KeyCode::CapsLock => if !is_held {
                 info.is_caps_lock_enabled = !info.is_caps_lock_enabled;
             }
 
+            KeyCode::NumLock => if !is_held {
+                info.is_num_lock_enabled = !info.is_num_lock_enabled;
+            }
+
+            KeyCode::ScrollLock => if !is_held {
+                info.is_scroll_lock_enabled = !info.is_scroll_lock_enabled;
+            }
+
             _ => ()
         }
 
         info.ext = ScanCodeExtension::Default;
 
-        Some(KeyEvent::Down(KeyInfo {
-            is_caps_lock_enabled: info.is_caps_lock_enabled,
-            is_shift_down: info.is_shift_down,
-            is_ctrl_down: info.is_ctrl_down,
-            is_alt_down: info.is_alt_down,
+        let code = *KEYS_DOWN.lock().entry(key_code).or_insert_with(|| info.translate(key_code));
 
-            is_held,
-
-            char: key_code.to_byte_unicode(info.is_caps_lock_enabled ^ info.is_shift_down)? as char,
-            code: key_code,
-        }))
+        info.key_info(code?, is_held).map(KeyEvent::Down)
     }
 }
 
@@ -135,6 +170,8 @@ pub enum KeyEvent {
 #[derive(Debug)]
 pub struct KeyInfo {
     pub is_caps_lock_enabled: bool,
+    pub is_num_lock_enabled: bool,
+    pub is_scroll_lock_enabled: bool,
     pub is_shift_down: bool,
     pub is_ctrl_down: bool,
     pub is_alt_down: bool,
@@ -142,4 +179,69 @@ pub struct KeyInfo {
     pub is_held: bool,
     pub char: char,
     pub code: KeyCode,
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn press(scan_code: u8) -> Option<KeyInfo> {
+        match handle_next_scan_code(scan_code)? {
+            KeyEvent::Down(key) => Some(key),
+            KeyEvent::Up(_) => panic!("{scan_code} is a press"),
+        }
+    }
+
+    fn release(scan_code: u8) -> Option<KeyInfo> {
+        match handle_next_scan_code(scan_code + 128)? {
+            KeyEvent::Up(key) => Some(key),
+            KeyEvent::Down(_) => panic!("{scan_code} is a release"),
+        }
+    }
+
+    // The only test going through the shared state, so nothing else presses keys in the meantime.
+    #[test]
+    fn num_lock_switches_the_keypad() {
+        let key = press(71).unwrap();
+        assert_eq!(key.code, KeyCode::Extended(ExtendedKeyCode::Home));
+        assert!(!key.is_num_lock_enabled);
+        release(71);
+
+        // Keypad 5 has nothing to navigate to.
+        assert!(press(76).is_none());
+        release(76);
+
+        let key = press(69).unwrap();
+        assert!(key.is_num_lock_enabled);
+        // Held down, so it doesn't toggle again.
+        assert!(press(69).unwrap().is_num_lock_enabled);
+        release(69);
+
+        let key = press(71).unwrap();
+        assert_eq!((key.code, key.char), (KeyCode::Keypad7, '7'));
+
+        // It goes up as Keypad 7 even if Num Lock goes off in between, and isn't left held.
+        press(69);
+        release(69);
+        assert_eq!(release(71).unwrap().code, KeyCode::Keypad7);
+
+        let key = press(71).unwrap();
+        assert_eq!(key.code, KeyCode::Extended(ExtendedKeyCode::Home));
+        assert!(!key.is_held);
+
+        // And the other way around.
+        press(69);
+        release(69);
+        assert_eq!(release(71).unwrap().code, KeyCode::Extended(ExtendedKeyCode::Home));
+
+        // Shift reverses Num Lock, which is on now.
+        press(42);
+        assert_eq!(press(83).unwrap().code, KeyCode::Extended(ExtendedKeyCode::Delete));
+        release(83);
+        release(42);
+
+        let key = press(70).unwrap();
+        assert!(key.is_scroll_lock_enabled && key.is_num_lock_enabled && !key.is_caps_lock_enabled);
+        release(70);
+    }
+}